@@ -185,7 +185,9 @@ function RealmGovernanceInfo() {
           </div>
           <div className="info-row">
             <label>Authority:</label>
-            <ShortPubkey address={(realmInfo.authority as PublicKey).toBase58()} />
+            {realmInfo.authority
+              ? <ShortPubkey address={(realmInfo.authority as PublicKey).toBase58()} />
+              : <span>无</span>}
           </div>
           <div className="info-row">
             <label>最小治理权:</label>
@@ -38,8 +38,8 @@ export const REALM_MINT = new web3.PublicKey(
     "3Wku9XAZhR2jx3PzG8cunGXxdkMHTzPfhaFxaaedoEBB"
 );
 
-// --- 社区目标账户 PDA（固定地址）---
+// --- 社区目标账户 PDA（固定地址，以社区 mint 作为被治理账户）---
 export const GOVERNANCE_PDA = web3.PublicKey.findProgramAddressSync(
-    [REALM_PDA.toBuffer(), Buffer.from("governance")],
+    [REALM_PDA.toBuffer(), Buffer.from("governance"), REALM_MINT.toBuffer()],
     PROGRAM_ID
 )[0];
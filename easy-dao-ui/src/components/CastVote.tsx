@@ -1,5 +1,5 @@
 import type { WalletContextState } from '@solana/wallet-adapter-react';
-import { getProgram, REALM_PDA, REALM_MINT, GOVERNANCE_PDA } from '../anchor';
+import { getProgram, PROGRAM_ID, REALM_PDA, REALM_MINT, GOVERNANCE_PDA } from '../anchor';
 import { PublicKey } from '@solana/web3.js';
 
 
@@ -18,6 +18,16 @@ export async function voteOnProposal(
     const proposalAccount = await program.account.proposal.fetch(proposalAddress);
     const tokenOwnerAccount = await program.account.tokenOwnerRecord.fetch(proposalAccount.tokenOwnerRecord);
     
+    const [voteTokenOwnerRecordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("governance"),
+        REALM_PDA.toBuffer(),
+        REALM_MINT.toBuffer(),
+        publicKey.toBuffer()
+      ],
+      PROGRAM_ID
+    );
+
     const voteArg: any = yes ? { yes: {} } : { no: {} };
     const tx = await program.methods.castVote(
         voteArg
@@ -29,6 +39,10 @@ export async function voteOnProposal(
         mint: REALM_MINT,
         authority: publicKey,
         user: tokenOwnerAccount.governingTokenOwner,
+        voteTokenOwnerRecord: voteTokenOwnerRecordPda,
+        tokenOwnerRecord: proposalAccount.tokenOwnerRecord,
+        voterWeightRecord: null,
+        maxVoterWeightRecord: null,
       } as any)
       .rpc();
 
@@ -1,6 +1,7 @@
 import React, { useState } from 'react';
 import { useWallet } from '@solana/wallet-adapter-react';
-import { getProgram, REALM_MINT, REALM_PDA } from '../anchor';
+import { PublicKey } from '@solana/web3.js';
+import { getProgram, PROGRAM_ID, REALM_MINT, REALM_PDA } from '../anchor';
 
 const CreateProposal: React.FC = () => {
   const { publicKey, wallet } = useWallet();
@@ -18,11 +19,26 @@ const CreateProposal: React.FC = () => {
     try {
       const program = getProgram(wallet.adapter ?? wallet);
       const authority = publicKey.toBase58();
-      const tx = await program.methods.createProposal(title, url)
+      const [tokenOwnerRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("governance"),
+          REALM_PDA.toBuffer(),
+          REALM_MINT.toBuffer(),
+          publicKey.toBuffer()
+        ],
+        PROGRAM_ID
+      );
+      const tx = await program.methods.createProposal(title, url, { singleChoice: {} }, ["通过"])
         .accounts({
           mint: REALM_MINT,
           authority,
           realm: REALM_PDA,
+          tokenOwnerRecord: tokenOwnerRecordPda,
+          voterWeightRecord: null,
+          proposerTokenAccount: null,
+          depositTokenAccount: null,
+          tokenProgram: null,
+          associatedTokenProgram: null,
         } as any)
         .rpc();
       setTxid(tx);
@@ -21,6 +21,7 @@ export async function finalizeVoteInstruction(
         realm: REALM_PDA,
         mint: REALM_MINT,
         user: publicKey,
+        maxVoterWeightRecord: null,
       } as any)
       .rpc();
 
@@ -51,6 +51,12 @@ const ProposalList: React.FC = () => {
         return '#42a5f5';
       case 'defeated':
         return '#e57373';
+      case 'vetoed':
+        return '#c62828';
+      case 'expired':
+        return '#bdbdbd';
+      case 'cancelled':
+        return '#757575';
       case 'executing':
         return '#ab47bc';
       case 'executionfailed':
@@ -214,12 +220,12 @@ const ProposalList: React.FC = () => {
             <h3 style={{ marginTop: 0 }}>提案详情</h3>
             <p><strong>标题:</strong> {selectedProposal.data.name || '(未命名)'}</p>
             <p><strong>状态:</strong> {getProposalStateName(selectedProposal.data.state)}</p>
-            <p><strong>Yes:</strong> {selectedProposal.data.yesVoteWeight.toString()}</p>
+            <p><strong>Yes:</strong> {selectedProposal.data.options[0].voteWeight.toString()}</p>
             <p><strong>No:</strong> {selectedProposal.data.noVoteWeight.toString()}</p>
             <p><strong>投票开始:</strong> {formatDate(selectedProposal.data.votingStartedAt)}</p>
             <p><strong>投票完成:</strong> {formatDate(selectedProposal.data.votingCompletedAt)}</p>
             <p><strong>提案关闭时间:</strong> {formatDate(selectedProposal.data.closedAt)}</p>
-            <p><strong>执行链上指令:</strong> {selectedProposal.data.hasTransaction ? '是' : '否'}</p>
+            <p><strong>执行链上指令:</strong> {selectedProposal.data.options.some((option: any) => option.transactionsCount > 0) ? '是' : '否'}</p>
             <p>
               <strong>描述链接:</strong>{' '}
               {selectedProposal.data.descriptionLink ? (
@@ -27,9 +27,6 @@ pub enum GovernanceError {
     #[msg("当前 VoteThreshold 类型尚不支持")]
     VoteThresholdTypeNotSupported,
 
-    #[msg("无效的免押金提案数量")]
-    InvalidDepositExemptProposalCount,
-
     #[msg("无权限，必须是 Realm 管理员")]
     UnauthorizedRealmAuthority,
 
@@ -110,4 +107,172 @@ pub enum GovernanceError {
 
     #[msg("无效的 MintMaxVoterWeightSource 配置")]
     InvalidMintMaxVoterWeightSource,
+
+    #[msg("取出的治理代币数量必须大于零且不超过存入数量")]
+    InvalidWithdrawAmount,
+
+    #[msg("存在未释放的投票，无法取出治理代币")]
+    AllVotesMustBeRelinquishedToWithdrawGoverningTokens,
+
+    #[msg("存在未结束的提案，无法取出治理代币")]
+    AllProposalsMustBeFinalisedToWithdrawGoverningTokens,
+
+    #[msg("TokenOwnerRecord 存在未过期的锁")]
+    TokenOwnerRecordLocked,
+
+    #[msg("成员制治理代币不允许取出")]
+    CannotWithdrawMembershipTokens,
+
+    #[msg("休眠治理代币不允许存入")]
+    CannotDepositDormantTokens,
+
+    #[msg("传入的 mint 不是 Realm 的治理代币")]
+    InvalidGoverningTokenMint,
+
+    #[msg("治理代币 mint 与提案不一致")]
+    InvalidGoverningMintForProposal,
+
+    #[msg("无效的提案选项索引")]
+    InvalidOptionIndex,
+
+    #[msg("交易索引必须等于提案当前的交易数量")]
+    InvalidTransactionIndex,
+
+    #[msg("交易至少需要包含一条指令")]
+    EmptyTransactionInstructions,
+
+    #[msg("交易必须按索引顺序执行")]
+    InvalidTransactionExecutionOrder,
+
+    #[msg("交易的锁定时长低于治理配置的最小值")]
+    TransactionHoldUpTimeBelowRequiredMin,
+
+    #[msg("交易仍处于锁定期内，暂不能执行")]
+    CannotExecuteTransactionWithinHoldUpTime,
+
+    #[msg("必须由治理代币拥有者或其代理人签名")]
+    GoverningTokenOwnerOrDelegateMustSign,
+
+    #[msg("签名者不是该治理代币配置的锁定权限人")]
+    InvalidLockAuthority,
+
+    #[msg("锁的过期时间必须晚于当前时间")]
+    ExpiredTokenOwnerRecordLock,
+
+    #[msg("治理账户的锁数量已达上限")]
+    TooManyTokenOwnerRecordLocks,
+
+    #[msg("未找到对应的锁")]
+    TokenOwnerRecordLockNotFound,
+
+    #[msg("缺少新的 Realm 治理权拥有者账户")]
+    NewRealmAuthorityRequired,
+
+    #[msg("新的 Realm 治理权拥有者必须是该 Realm 下的 Governance 账户")]
+    InvalidNewRealmAuthority,
+
+    #[msg("锁定权限人数量超出上限")]
+    TooManyLockAuthorities,
+
+    #[msg("用户持有的治理代币不足，无法创建治理账户")]
+    InsufficientWeightToCreateGovernance,
+
+    #[msg("只有理事会代币持有人可以对社区提案投否决票")]
+    InvalidVetoVote,
+
+    #[msg("提案选项数量或标签长度无效")]
+    InvalidProposalOptions,
+
+    #[msg("无效的投票类型配置")]
+    InvalidVoteType,
+
+    #[msg("投票选择与提案选项不匹配")]
+    InvalidVote,
+
+    #[msg("该选项未通过，不能执行其交易")]
+    CannotExecuteDefeatedOption,
+
+    #[msg("冷却期内只能投反对票或否决票")]
+    VoteNotAllowedInCoolOffTime,
+
+    #[msg("冷却期内只能撤回赞成票")]
+    CannotRelinquishInVotingPeriod,
+
+    #[msg("已配置投票权插件，必须提供 VoterWeightRecord 账户")]
+    MissingVoterWeightRecord,
+
+    #[msg("无效的 VoterWeightRecord 账户")]
+    InvalidVoterWeightRecord,
+
+    #[msg("VoterWeightRecord 已过期")]
+    VoterWeightRecordExpired,
+
+    #[msg("VoterWeightRecord 不适用于当前操作")]
+    InvalidVoterWeightAction,
+
+    #[msg("已配置最大票权插件，必须提供 MaxVoterWeightRecord 账户")]
+    MissingMaxVoterWeightRecord,
+
+    #[msg("无效的 MaxVoterWeightRecord 账户")]
+    InvalidMaxVoterWeightRecord,
+
+    #[msg("MaxVoterWeightRecord 已过期")]
+    MaxVoterWeightRecordExpired,
+
+    #[msg("租金退还账户与记录的租金支付者不一致")]
+    InvalidRentPayer,
+
+    #[msg("投票记录尚未释放，不能关闭")]
+    VoteRecordNotRelinquished,
+
+    #[msg("提案签署尚未结束，不能关闭签名记录")]
+    CannotCloseSignatoryRecord,
+
+    #[msg("只能移除选项中的最后一条指令")]
+    CanOnlyRemoveLastTransaction,
+
+    #[msg("代币持有记录仍有存款、未释放投票或未结束提案，不能关闭")]
+    TokenOwnerRecordNotEmpty,
+
+    #[msg("押金以治理代币支付，必须提供代币账户与代币程序")]
+    MissingProposalDepositTokenAccount,
+
+    #[msg("无效的押金罚没投票率，需在 0 到 100 之间")]
+    InvalidDepositSlashTurnoutPercentage,
+
+    #[msg("押金需罚没进治理金库，必须提供金库账户")]
+    MissingNativeTreasury,
+
+    #[msg("提案草稿最长存续时间必须大于 0")]
+    InvalidMaxDraftTime,
+
+    #[msg("提案草稿已超过最长存续时间")]
+    ProposalDraftExpired,
+
+    #[msg("提案草稿尚未超过最长存续时间")]
+    ProposalDraftNotExpired,
+
+    #[msg("交易的重试期限已过，不能再执行")]
+    TransactionRetryTimeExpired,
+
+    #[msg("该交易已被标记为执行失败")]
+    TransactionAlreadyFlagged,
+
+    #[msg("理事会代币 mint 不能与社区代币 mint 相同")]
+    InvalidCouncilMint,
+
+    #[msg("理事会代币 mint 与理事会代币托管账户必须同时提供")]
+    InvalidCouncilTokenAccount,
+
+    #[msg("该记录创建过提案，不能关闭")]
+    TokenOwnerRecordHasProposals,
+
+    #[msg("以治理代币支付押金时必须配置押金数额")]
+    MissingProposalDepositAmount,
+
+    #[msg("交易可执行后的执行窗口尚未结束，不能标记为执行失败")]
+    CannotFlagTransactionWithinExecutionWindow,
+
+    #[msg("remaining_accounts 中的程序账户与指令的 program_id 不一致")]
+    InvalidInstructionProgram,
 }
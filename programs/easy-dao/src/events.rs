@@ -0,0 +1,169 @@
+//! 指令事件，供链下索引器订阅
+use anchor_lang::prelude::*;
+
+use crate::{ExecutionStatus, ProposalDepositType, ProposalState, Vote};
+
+
+/// 创建治理域
+#[event]
+pub struct RealmCreated {
+    pub realm: Pubkey,
+    pub community_mint: Pubkey,
+    pub council_mint: Option<Pubkey>,
+    pub authority: Pubkey,
+    pub created_at: i64,
+}
+
+/// 存入治理代币
+#[event]
+pub struct GoverningTokensDeposited {
+    pub realm: Pubkey,
+    pub governing_token_mint: Pubkey,
+    pub governing_token_owner: Pubkey,
+    pub token_owner_record: Pubkey,
+    pub amount: u64,
+    /// 存入后的总存款数量
+    pub governing_token_deposit_amount: u64,
+    pub deposited_at: i64,
+}
+
+/// 取出治理代币
+#[event]
+pub struct GoverningTokensWithdrawn {
+    pub realm: Pubkey,
+    pub governing_token_mint: Pubkey,
+    pub governing_token_owner: Pubkey,
+    pub token_owner_record: Pubkey,
+    pub amount: u64,
+    /// 取出后剩余的存款数量
+    pub governing_token_deposit_amount: u64,
+    pub withdrawn_at: i64,
+}
+
+/// 创建提案
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub governance: Pubkey,
+    pub token_owner_record: Pubkey,
+    pub governing_token_mint: Pubkey,
+    pub proposer: Pubkey,
+    pub created_at: i64,
+}
+
+/// 添加签署人
+#[event]
+pub struct SignatoryAdded {
+    pub proposal: Pubkey,
+    pub signatory: Pubkey,
+    pub signatories_count: u8,
+    pub added_at: i64,
+}
+
+/// 签署提案
+#[event]
+pub struct ProposalSignedOff {
+    pub proposal: Pubkey,
+    pub signatory: Pubkey,
+    pub signatories_signed_off_count: u8,
+    pub signed_off_at: i64,
+}
+
+/// 所有签署人签署完成，提案进入投票
+#[event]
+pub struct VotingStarted {
+    pub proposal: Pubkey,
+    pub governance: Pubkey,
+    pub voting_started_at: i64,
+}
+
+/// 草稿超时未进入投票，提案过期
+#[event]
+pub struct ProposalExpired {
+    pub proposal: Pubkey,
+    pub governance: Pubkey,
+    pub draft_at: i64,
+    pub expired_at: i64,
+}
+
+/// 发起人取消提案
+#[event]
+pub struct ProposalCancelled {
+    pub proposal: Pubkey,
+    pub governance: Pubkey,
+    pub token_owner_record: Pubkey,
+    /// 取消前的提案状态
+    pub previous_state: ProposalState,
+    pub cancelled_at: i64,
+}
+
+/// 投票
+#[event]
+pub struct VoteCast {
+    pub proposal: Pubkey,
+    pub vote_record: Pubkey,
+    pub token_owner_record: Pubkey,
+    pub governing_token_owner: Pubkey,
+    pub vote: Vote,
+    pub vote_weight: u64,
+    pub voted_at: i64,
+}
+
+/// 投票结束（投票中提前结束或 finalize）
+#[event]
+pub struct VoteFinalized {
+    pub proposal: Pubkey,
+    pub state: ProposalState,
+    /// 是否在投票期内提前结束
+    pub tipped: bool,
+    pub max_voter_weight: u128,
+    pub voting_completed_at: Option<i64>,
+}
+
+/// 执行提案交易
+#[event]
+pub struct TransactionExecuted {
+    pub proposal: Pubkey,
+    pub proposal_transaction: Pubkey,
+    pub option_index: u8,
+    pub transaction_index: u16,
+    pub execution_status: ExecutionStatus,
+    pub executed_at: i64,
+}
+
+/// 提案发起人将无法执行的交易标记为失败
+#[event]
+pub struct TransactionErrorFlagged {
+    pub proposal: Pubkey,
+    pub proposal_transaction: Pubkey,
+    pub option_index: u8,
+    pub transaction_index: u16,
+    /// 允许重试执行的截止时间
+    pub retry_deadline: i64,
+    pub flagged_at: i64,
+}
+
+/// 释放投票
+#[event]
+pub struct VoteRelinquished {
+    pub proposal: Pubkey,
+    pub vote_record: Pubkey,
+    pub token_owner_record: Pubkey,
+    pub vote_weight: u64,
+    /// 是否在投票期内撤回（票权已从计票中扣除）
+    pub withdrawn_from_tally: bool,
+    pub relinquished_at: i64,
+}
+
+/// 退还提案押金
+#[event]
+pub struct ProposalDepositRefunded {
+    pub proposal: Pubkey,
+    pub proposal_deposit: Pubkey,
+    pub deposit_payer: Pubkey,
+    pub deposit_type: ProposalDepositType,
+    pub amount: u64,
+    /// 押金是否被罚没进治理金库
+    pub slashed: bool,
+    pub refunded_at: i64,
+}
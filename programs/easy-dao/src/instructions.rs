@@ -1,31 +1,61 @@
 pub mod create_realm;
+pub mod set_realm_authority;
+pub mod set_realm_config;
 pub mod create_token_owner_record;
 pub mod deposit_governing_tokens;
+pub mod withdraw_governing_tokens;
+pub mod set_governance_delegate;
+pub mod set_token_owner_record_lock;
+pub mod relinquish_token_owner_record_lock;
+pub mod close_token_owner_record;
 pub mod create_governance;
+pub mod set_governance_config;
+pub mod create_native_treasury;
 pub mod create_proposal;
+pub mod cancel_proposal;
+pub mod expire_draft_proposal;
 pub mod create_required_signatory;
 pub mod remove_required_signatory;
 pub mod add_signatory;
+pub mod close_signatory_record;
 pub mod sign_off_proposal;
 pub mod cast_vote;
 pub mod finalize_vote;
 pub mod relinquish_vote;
+pub mod close_vote_record;
 pub mod add_transaction;
+pub mod remove_transaction;
 pub mod execute_transaction;
+pub mod flag_transaction_error;
 pub mod refund_proposal_deposit;
 
 pub use create_realm::*;
+pub use set_realm_authority::*;
+pub use set_realm_config::*;
 pub use create_token_owner_record::*;
 pub use deposit_governing_tokens::*;
+pub use withdraw_governing_tokens::*;
+pub use set_governance_delegate::*;
+pub use set_token_owner_record_lock::*;
+pub use relinquish_token_owner_record_lock::*;
+pub use close_token_owner_record::*;
 pub use create_governance::*;
+pub use set_governance_config::*;
+pub use create_native_treasury::*;
 pub use create_proposal::*;
+pub use cancel_proposal::*;
+pub use expire_draft_proposal::*;
 pub use create_required_signatory::*;
 pub use remove_required_signatory::*;
 pub use add_signatory::*;
+pub use close_signatory_record::*;
 pub use sign_off_proposal::*;
 pub use cast_vote::*;
 pub use finalize_vote::*;
 pub use relinquish_vote::*;
+pub use close_vote_record::*;
 pub use add_transaction::*;
+pub use remove_transaction::*;
 pub use execute_transaction::*;
+pub use flag_transaction_error::*;
 pub use refund_proposal_deposit::*;
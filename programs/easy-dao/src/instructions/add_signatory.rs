@@ -2,7 +2,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    error::GovernanceError, Governance, GovernanceAccountType, Proposal, ProposalState, Realm, RequiredSignatory, SignatoryRecord, TokenOwnerRecord
+    error::GovernanceError, events::SignatoryAdded, Governance, GovernanceAccountType, Proposal, ProposalState, Realm, RequiredSignatory, SignatoryRecord, TokenOwnerRecord
 };
 
 
@@ -20,8 +20,8 @@ pub struct AddSignatory<'info> {
     pub governance: Account<'info, Governance>,
 
     #[account(
-        constraint = aut_record.governing_token_owner == authority.key() 
-            @ GovernanceError::InvalidProposalTokenOwnerRecord
+        constraint = aut_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign
     )]
     pub aut_record: Account<'info, TokenOwnerRecord>,
 
@@ -91,6 +91,14 @@ impl<'info> AddSignatory<'info> {
         signatory_record.proposal = self.proposal.key();
         signatory_record.signatory = self.signatory.key();
         signatory_record.signed_off = false;
+        signatory_record.rent_payer = self.authority.key();
+
+        emit!(SignatoryAdded {
+            proposal: self.proposal.key(),
+            signatory: self.signatory.key(),
+            signatories_count: self.proposal.signatories_count,
+            added_at: Clock::get()?.unix_timestamp,
+        });
 
         Ok(())
     }
@@ -1,23 +1,26 @@
 use anchor_lang::prelude::*;
 
-use crate::{error::GovernanceError, ExecutionStatus, GovernanceAccountType, InstructionData, Proposal, ProposalState, ProposalTransaction, TokenOwnerRecord};
+use crate::{error::GovernanceError, ExecutionStatus, Governance, GovernanceAccountType, InstructionData, Proposal, ProposalState, ProposalTransaction, TokenOwnerRecord};
 
 
 #[derive(Accounts)]
-#[instruction(instruction_data: InstructionData)]
+#[instruction(option_index: u8, transaction_index: u16, hold_up_time: u32, instructions: Vec<InstructionData>)]
 pub struct AddTransaction<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
-    
+
     #[account(
         mut,
-        constraint = token_owner_record.governing_token_owner == authority.key() 
-            @ GovernanceError::InvalidTokenOwnerRecordOwner,
+        constraint = token_owner_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign,
     )]
     pub token_owner_record: Account<'info, TokenOwnerRecord>,
-    
+
+    pub governance: Account<'info, Governance>,
+
     #[account(
         mut,
+        has_one = governance @ GovernanceError::InvalidGovernanceForAccount,
         has_one = token_owner_record @ GovernanceError::InvalidTokenOwnerRecordOwner,
     )]
     pub proposal: Account<'info, Proposal>,
@@ -25,11 +28,12 @@ pub struct AddTransaction<'info> {
     #[account(
         init,
         payer = authority,
-        space = ProposalTransaction::FIXED_LEN 
-            + instruction_data.serialized_len(),
+        space = ProposalTransaction::get_space(&instructions),
         seeds = [
             ProposalTransaction::SEED_PREFIX,
             proposal.key().as_ref(),
+            option_index.to_le_bytes().as_ref(),
+            transaction_index.to_le_bytes().as_ref(),
         ],
         bump,
     )]
@@ -40,24 +44,48 @@ pub struct AddTransaction<'info> {
 
 impl<'info> AddTransaction<'info> {
     pub fn process(
-        &mut self, 
-        instruction_data: InstructionData
+        &mut self,
+        option_index: u8,
+        transaction_index: u16,
+        hold_up_time: u32,
+        instructions: Vec<InstructionData>
     ) -> Result<()> {
         require!(
-            self.proposal.state == ProposalState::Draft, 
+            self.proposal.state == ProposalState::Draft,
             GovernanceError::InvalidProposalState
         );
 
-        require!(!self.proposal.has_transaction, GovernanceError::TransactionAlreadyExists);
-        
-        self.proposal.has_transaction = true;
+        let option = self.proposal.options
+            .get_mut(option_index as usize)
+            .ok_or(GovernanceError::InvalidOptionIndex)?;
+
+        require!(
+            transaction_index == option.transactions_count,
+            GovernanceError::InvalidTransactionIndex
+        );
+
+        require!(!instructions.is_empty(), GovernanceError::EmptyTransactionInstructions);
+
+        require!(
+            hold_up_time >= self.governance.config.transactions_hold_up_time,
+            GovernanceError::TransactionHoldUpTimeBelowRequiredMin
+        );
+
+        option.transactions_count = option.transactions_count
+            .checked_add(1)
+            .ok_or(GovernanceError::Overflow)?;
 
         self.proposal_transaction.account_type = GovernanceAccountType::ProposalTransaction;
         self.proposal_transaction.proposal = self.proposal.key();
-        self.proposal_transaction.instruction = instruction_data;
+        self.proposal_transaction.option_index = option_index;
+        self.proposal_transaction.transaction_index = transaction_index;
+        self.proposal_transaction.hold_up_time = hold_up_time;
+        self.proposal_transaction.instructions = instructions;
         self.proposal_transaction.executed_at = None;
         self.proposal_transaction.execution_status = ExecutionStatus::Pending;
-        
+        self.proposal_transaction.error_flagged_at = None;
+        self.proposal_transaction.rent_payer = self.authority.key();
+
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,70 @@
+//! 取消提案指令
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceError,
+    events::ProposalCancelled,
+    Governance,
+    Proposal,
+    ProposalState,
+    Realm,
+    TokenOwnerRecord
+};
+
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    pub authority: Signer<'info>,
+
+    pub realm: Account<'info, Realm>,
+
+    #[account(
+        mut,
+        has_one = realm @ GovernanceError::InvalidGovernanceRealm
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        has_one = realm @ GovernanceError::InvalidTokenOwnerRecordRealm,
+        constraint = token_owner_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign
+    )]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+
+    #[account(
+        mut,
+        has_one = governance @ GovernanceError::InvalidGovernanceForAccount,
+        has_one = token_owner_record
+            @ GovernanceError::InvalidProposalTokenOwnerRecord
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+
+impl<'info> CancelProposal<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.proposal.assert_can_cancel(&self.governance.config)?;
+
+        let now: u64 = Clock::get()?.unix_timestamp.try_into()?;
+        let previous_state = self.proposal.state.clone();
+
+        self.proposal.state = ProposalState::Cancelled;
+        self.proposal.closed_at = Some(now);
+
+        self.token_owner_record.decrease_outstanding_proposal_count()?;
+        self.governance.active_proposal_count = self.governance.active_proposal_count
+            .checked_sub(1)
+            .ok_or(error!(GovernanceError::Overflow))?;
+
+        emit!(ProposalCancelled {
+            proposal: self.proposal.key(),
+            governance: self.governance.key(),
+            token_owner_record: self.token_owner_record.key(),
+            previous_state,
+            cancelled_at: now.try_into()?,
+        });
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
-use crate::{error::GovernanceError, Governance, GovernanceAccountType, Proposal, Realm, TokenOwnerRecord, Vote, VoteRecord};
+use crate::{error::GovernanceError, events::{VoteCast, VoteFinalized}, Governance, GovernanceAccountType, Proposal, Realm, RealmConfigAccount, TokenOwnerRecord, Vote, VoteRecord, VoterWeightAction};
 
 
 #[derive(Accounts)]
@@ -13,7 +13,21 @@ pub struct CastVote<'info> {
 
     pub realm: Account<'info, Realm>,
 
-    #[account(address = realm.community_mint)]
+    #[account(
+        seeds = [
+            realm.id.to_le_bytes().as_ref(),
+            RealmConfigAccount::REALM_CONFIG_SEEDS
+        ],
+        bump,
+        constraint = realm_config_account.realm == realm.key()
+            @ GovernanceError::InvalidRealmConfigAccount
+    )]
+    pub realm_config_account: Box<Account<'info, RealmConfigAccount>>,
+
+    #[account(
+        constraint = realm.is_governing_token_mint(&mint.key())
+            @ GovernanceError::InvalidGoverningTokenMint
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
@@ -26,7 +40,7 @@ pub struct CastVote<'info> {
         mut,
         has_one = governance @ GovernanceError::InvalidGovernanceForAccount,
         has_one = token_owner_record 
-            @ GovernanceError::InvalidProposalTokenOwnerRecord
+            @ GovernanceError::InvalidProposalTokenOwnerRecord,
     )]
     pub proposal: Account<'info, Proposal>,
 
@@ -36,24 +50,25 @@ pub struct CastVote<'info> {
             TokenOwnerRecord::RECORD_SEED,
             realm.key().as_ref(),
             mint.key().as_ref(),
-            authority.key().as_ref(),
+            vote_token_owner_record.governing_token_owner.as_ref(),
         ],
         bump,
         has_one = realm @ GovernanceError::InvalidTokenOwnerRecordRealm,
-        constraint = vote_token_owner_record.governing_token_owner == authority.key() 
-            @ GovernanceError::InvalidTokenOwnerRecordOwner
+        constraint = vote_token_owner_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign
     )]
     pub vote_token_owner_record: Account<'info, TokenOwnerRecord>,
 
-    /// todo: 如果 user == authority，那么账户重复了
+    /// todo: 如果投票人即提案发起人，那么账户重复了
     /// todo: token_owner_record 必须在 vote_token_owner_record 之后
     /// 防止两个是同一个账户是发生覆盖
+    /// 否决票使用理事会 mint，发起人账户仍按提案的 mint 派生
     #[account(
         mut,
         seeds = [
             TokenOwnerRecord::RECORD_SEED,
             realm.key().as_ref(),
-            mint.key().as_ref(),
+            proposal.governing_token_mint.as_ref(),
             user.key().as_ref(),
         ],
         bump,
@@ -76,48 +91,87 @@ pub struct CastVote<'info> {
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
+    /// CHECK: 投票权插件的 VoterWeightRecord，未配置插件时不传，在指令中校验归属与内容
+    pub voter_weight_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: 最大票权插件的 MaxVoterWeightRecord，未配置插件时不传，在指令中校验归属与内容
+    pub max_voter_weight_record: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>
 }
 
 
 impl<'info> CastVote<'info> {
     pub fn process(&mut self, vote: Vote) -> Result<()> {
-        self.governance.resolve_vote_threshold()?;
-
-        self.proposal.assert_can_cast_vote(&self.governance.config)?;
-
-        match vote {
-            Vote::Yes => {
-                self.proposal.yes_vote_weight = self.proposal.yes_vote_weight
-                    .checked_add(self.vote_token_owner_record.governing_token_deposit_amount)
-                    .ok_or(error!(GovernanceError::Overflow))?;
-            },
-            Vote::No => {
-                self.proposal.no_vote_weight = self.proposal.no_vote_weight
-                    .checked_add(self.vote_token_owner_record.governing_token_deposit_amount)
-                    .ok_or(error!(GovernanceError::Overflow))?;
-            }
-        }
-
-        let max_voter_weight = self.proposal
-            .get_max_voter_weight_from_mint_supply(
-                self.mint.supply,
-                self.mint.decimals,
-                self.realm.config.community_mint_max_voter_weight_source.clone()
-            ).ok_or(error!(GovernanceError::Overflow))?;
-
-        if self.proposal.maybe_finalize_vote(
-            max_voter_weight, 
-            self.governance.config.community_vote_threshold.clone()
+        let mint = self.mint.key();
+
+        // 否决票只能由理事会代币持有人对社区提案投出，其余投票必须使用提案的 mint
+        let vote_threshold = if vote == Vote::Veto {
+            require!(
+                self.realm.is_council_mint(&mint) && self.proposal.governing_token_mint != mint,
+                GovernanceError::InvalidVetoVote
+            );
+            self.governance.resolve_veto_vote_threshold()?
+        } else {
+            require_keys_eq!(
+                self.proposal.governing_token_mint,
+                mint,
+                GovernanceError::InvalidGoverningMintForProposal
+            );
+            self.governance.resolve_vote_threshold(&self.realm, &mint)?
+        };
+
+        self.proposal.assert_can_cast_vote(&self.governance.config, &vote)?;
+
+        // 投票时快照票权，之后存款变化不影响已投出的票
+        let vote_weight = self.realm_config_account.resolve_voter_weight(
+            &self.realm,
+            &self.vote_token_owner_record,
+            self.voter_weight_record.as_ref().map(|record| record.as_ref()),
+            VoterWeightAction::CastVote,
+            &self.proposal.key()
+        )?;
+        self.proposal.add_vote(&vote, vote_weight)?;
+
+        let max_voter_weight = match self.realm_config_account.resolve_max_voter_weight_from_addin(
+            &self.realm,
+            &mint,
+            self.max_voter_weight_record.as_ref().map(|record| record.as_ref())
         )? {
+            Some(max_voter_weight) => max_voter_weight,
+            None => self.proposal
+                .get_max_voter_weight_from_mint_supply(
+                    self.mint.supply,
+                    self.realm.get_mint_max_voter_weight_source(&mint)
+                ).ok_or(error!(GovernanceError::Overflow))?,
+        };
+
+        let finalized = if vote == Vote::Veto {
+            self.proposal.maybe_veto(max_voter_weight, vote_threshold)?
+        } else {
+            let vote_tipping = self.governance.get_vote_tipping(&self.realm, &mint);
+            self.proposal.maybe_finalize_vote(max_voter_weight, vote_threshold, vote_tipping)?
+        };
+
+        if finalized {
             self.token_owner_record.decrease_outstanding_proposal_count()?;
             
             self.governance.active_proposal_count = self.governance.active_proposal_count
                 .checked_sub(1)
                 .ok_or(error!(GovernanceError::Overflow))?; 
+
+            emit!(VoteFinalized {
+                proposal: self.proposal.key(),
+                state: self.proposal.state.clone(),
+                tipped: true,
+                max_voter_weight,
+                voting_completed_at: self.proposal.voting_completed_at
+                    .map(i64::try_from)
+                    .transpose()?,
+            });
         }
 
-        if self.user.key() == self.authority.key() {
+        if self.token_owner_record.key() == self.vote_token_owner_record.key() {
             self.token_owner_record.unrelinquished_votes_count = self.token_owner_record
             .unrelinquished_votes_count
             .checked_add(1)
@@ -134,9 +188,20 @@ impl<'info> CastVote<'info> {
         vote_record.proposal = self.proposal.key();
         vote_record.governing_token_owner = self.vote_token_owner_record.key();
         vote_record.is_relinquished = false;
-        vote_record.vote_weight = self.vote_token_owner_record.governing_token_deposit_amount;
-        vote_record.vote = vote;
-        
+        vote_record.vote_weight = vote_weight;
+        vote_record.rent_payer = self.authority.key();
+        vote_record.vote = vote.clone();
+
+        emit!(VoteCast {
+            proposal: self.proposal.key(),
+            vote_record: self.vote_record.key(),
+            token_owner_record: self.vote_token_owner_record.key(),
+            governing_token_owner: self.vote_token_owner_record.governing_token_owner,
+            vote,
+            vote_weight,
+            voted_at: Clock::get()?.unix_timestamp,
+        });
+
         Ok(())
     }
 }
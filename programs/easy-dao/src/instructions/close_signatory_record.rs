@@ -0,0 +1,37 @@
+//! 关闭签名记录指令
+use anchor_lang::prelude::*;
+
+use crate::{error::GovernanceError, Proposal, ProposalState, SignatoryRecord};
+
+
+#[derive(Accounts)]
+pub struct CloseSignatoryRecord<'info> {
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = proposal @ GovernanceError::InvalidGovernanceForAccount,
+    )]
+    pub signatory_record: Account<'info, SignatoryRecord>,
+
+    /// 添加签署人时支付租金的账户，关闭后租金退还给它
+    #[account(
+        mut,
+        address = signatory_record.rent_payer @ GovernanceError::InvalidRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+
+impl<'info> CloseSignatoryRecord<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 签署阶段结束后签名记录不再被读取，任何人都可以关闭
+        require!(
+            !matches!(self.proposal.state, ProposalState::Draft | ProposalState::SigningOff),
+            GovernanceError::CannotCloseSignatoryRecord
+        );
+
+        Ok(())
+    }
+}
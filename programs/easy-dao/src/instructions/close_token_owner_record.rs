@@ -0,0 +1,32 @@
+//! 关闭代币持有记录指令
+use anchor_lang::prelude::*;
+
+use crate::{error::GovernanceError, TokenOwnerRecord};
+
+
+#[derive(Accounts)]
+pub struct CloseTokenOwnerRecord<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        constraint = token_owner_record.governing_token_owner == authority.key()
+            @ GovernanceError::InvalidTokenOwnerRecordOwner
+    )]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+
+    /// 创建记录时支付租金的账户，关闭后租金退还给它
+    #[account(
+        mut,
+        address = token_owner_record.rent_payer @ GovernanceError::InvalidRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+
+impl<'info> CloseTokenOwnerRecord<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.token_owner_record.assert_can_close()
+    }
+}
@@ -0,0 +1,40 @@
+//! 关闭投票记录指令
+use anchor_lang::prelude::*;
+
+use crate::{error::GovernanceError, TokenOwnerRecord, VoteRecord};
+
+
+#[derive(Accounts)]
+pub struct CloseVoteRecord<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = token_owner_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign
+    )]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        constraint = vote_record.governing_token_owner == token_owner_record.key()
+            @ GovernanceError::InvalidTokenOwnerRecordOwner,
+        constraint = vote_record.is_relinquished
+            @ GovernanceError::VoteRecordNotRelinquished
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// 投票时支付租金的账户，关闭后租金退还给它
+    #[account(
+        mut,
+        address = vote_record.rent_payer @ GovernanceError::InvalidRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+
+impl<'info> CloseVoteRecord<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 //! 管理目标账户创建指令
 use anchor_lang::prelude::*;
 
-use crate::{error::GovernanceError, Governance, GovernanceAccountType, GovernanceConfig, Realm};
+use crate::{error::GovernanceError, Governance, GovernanceAccountType, GovernanceConfig, Realm, RealmConfigAccount, TokenOwnerRecord, VoterWeightAction};
 
 
 #[derive(Accounts)]
@@ -9,10 +9,30 @@ pub struct CreateGovernance<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    pub realm: Account<'info, Realm>,
+
     #[account(
-        has_one = authority @ GovernanceError::UnauthorizedRealmAuthority
+        seeds = [
+            realm.id.to_le_bytes().as_ref(),
+            RealmConfigAccount::REALM_CONFIG_SEEDS
+        ],
+        bump,
+        constraint = realm_config_account.realm == realm.key()
+            @ GovernanceError::InvalidRealmConfigAccount
     )]
-    pub realm: Account<'info, Realm>,
+    pub realm_config_account: Box<Account<'info, RealmConfigAccount>>,
+
+    /// 非 Realm 管理员创建时，需提供持有足够治理代币的用户治理账户
+    #[account(
+        constraint = token_owner_record.realm == realm.key()
+            @ GovernanceError::InvalidTokenOwnerRecordRealm,
+        constraint = token_owner_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign
+    )]
+    pub token_owner_record: Option<Account<'info, TokenOwnerRecord>>,
+
+    /// CHECK: 被治理账户，仅作为 Governance PDA 的派生种子
+    pub governed_account: UncheckedAccount<'info>,
 
     #[account(
         init,
@@ -20,12 +40,16 @@ pub struct CreateGovernance<'info> {
         space = Governance::LEN,
         seeds = [
             realm.key().as_ref(),
-            Governance::GOVERNANCE_SEED
+            Governance::GOVERNANCE_SEED,
+            governed_account.key().as_ref()
         ],
         bump
     )]
     pub governance: Account<'info, Governance>,
 
+    /// CHECK: 投票权插件的 VoterWeightRecord，未配置插件时不传，在指令中校验归属与内容
+    pub voter_weight_record: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>
 }
 
@@ -36,16 +60,47 @@ impl<'info> CreateGovernance<'info> {
         bump_governance: u8,
         governance_config: GovernanceConfig
     ) -> Result<()> {
-        Governance::assert_is_valid_vote_threshold(&governance_config.community_vote_threshold)?;
+        self.assert_can_create_governance()?;
+
+        Governance::assert_is_valid_governance_config(&governance_config)?;
 
         let governance = &mut self.governance;
         governance.account_type = GovernanceAccountType::Governance;
         governance.realm = self.realm.key();
-        governance.governance_seed = bump_governance;
+        governance.governed_account = self.governed_account.key();
+        governance.bump = bump_governance;
         governance.config = governance_config;
         governance.required_signatories_count = 0;
         governance.active_proposal_count = 0;
 
-        Ok(()) 
+        Ok(())
+    }
+
+    /// Realm 管理员可直接创建；其他用户需满足最小治理代币权重
+    fn assert_can_create_governance(&self) -> Result<()> {
+        if self.realm.is_realm_authority(&self.authority.key()) {
+            return Ok(());
+        }
+
+        let token_owner_record = self.token_owner_record
+            .as_ref()
+            .ok_or(GovernanceError::UnauthorizedRealmAuthority)?;
+
+        let voter_weight = self.realm_config_account.resolve_voter_weight(
+            &self.realm,
+            token_owner_record,
+            self.voter_weight_record.as_ref().map(|record| record.as_ref()),
+            VoterWeightAction::CreateGovernance,
+            &self.realm.key()
+        )?;
+        let has_enough_weight = if self.realm.is_council_mint(&token_owner_record.governing_token_mint) {
+            voter_weight > 0
+        } else {
+            voter_weight >= self.realm.config.min_community_weight_to_create_governance
+        };
+
+        require!(has_enough_weight, GovernanceError::InsufficientWeightToCreateGovernance);
+
+        Ok(())
     }
 }
@@ -0,0 +1,47 @@
+//! 创建治理原生金库
+use anchor_lang::{prelude::*, system_program};
+
+use crate::Governance;
+
+
+#[derive(Accounts)]
+pub struct CreateNativeTreasury<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [
+            Governance::NATIVE_TREASURY_SEED,
+            governance.key().as_ref()
+        ],
+        bump
+    )]
+    pub native_treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+
+impl<'info> CreateNativeTreasury<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 金库是无数据的系统账户，只需存入免租金的最低余额即可持有 SOL
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(0);
+        let lamports = self.native_treasury.lamports();
+
+        if lamports < rent_exempt_lamports {
+            let cpi_ctx = CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.payer.to_account_info(),
+                    to: self.native_treasury.to_account_info(),
+                },
+            );
+            system_program::transfer(cpi_ctx, rent_exempt_lamports - lamports)?;
+        }
+
+        Ok(())
+    }
+}
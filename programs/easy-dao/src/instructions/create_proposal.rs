@@ -1,17 +1,33 @@
 //! 创建提案账户
 
 use anchor_lang::{prelude::*, system_program};
-use anchor_spl::token_interface::Mint;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        Mint,
+        TokenAccount,
+        TokenInterface,
+        transfer_checked,
+        TransferChecked
+    }
+};
 
 use crate::{
     error::GovernanceError, 
+    events::ProposalCreated,
     Governance, 
     GovernanceAccountType, 
+    OptionVoteResult,
     Proposal, 
     ProposalDeposit, 
+    ProposalDepositType,
+    ProposalOption,
     ProposalState, 
     Realm, 
-    TokenOwnerRecord
+    RealmConfigAccount,
+    TokenOwnerRecord,
+    VoterWeightAction,
+    VoteType
 };
 
 
@@ -23,17 +39,25 @@ pub struct CreateProposal<'info> {
     pub realm: Account<'info, Realm>,
 
     #[account(
-        address = realm.community_mint
+        seeds = [
+            realm.id.to_le_bytes().as_ref(),
+            RealmConfigAccount::REALM_CONFIG_SEEDS
+        ],
+        bump,
+        constraint = realm_config_account.realm == realm.key()
+            @ GovernanceError::InvalidRealmConfigAccount
+    )]
+    pub realm_config_account: Box<Account<'info, RealmConfigAccount>>,
+
+    #[account(
+        constraint = realm.is_governing_token_mint(&mint.key())
+            @ GovernanceError::InvalidGoverningTokenMint
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
-        seeds = [
-            realm.key().as_ref(),
-            Governance::GOVERNANCE_SEED
-        ],
-        bump
+        has_one = realm @ GovernanceError::InvalidGovernanceRealm
     )]
     pub governance: Account<'info, Governance>,
 
@@ -43,12 +67,12 @@ pub struct CreateProposal<'info> {
             TokenOwnerRecord::RECORD_SEED,
             realm.key().as_ref(),
             mint.key().as_ref(),
-            authority.key().as_ref(),
+            token_owner_record.governing_token_owner.as_ref(),
         ],
         bump,
         has_one = realm @ GovernanceError::InvalidTokenOwnerRecordRealm,
-        constraint = token_owner_record.governing_token_owner == authority.key() 
-            @ GovernanceError::InvalidTokenOwnerRecordOwner
+        constraint = token_owner_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign
     )]
     pub token_owner_record: Account<'info, TokenOwnerRecord>,
 
@@ -78,28 +102,67 @@ pub struct CreateProposal<'info> {
     )]
     pub proposal_deposit: Account<'info, ProposalDeposit>,
 
-    pub system_program: Program<'info, System>
+    /// CHECK: 投票权插件的 VoterWeightRecord，未配置插件时不传，在指令中校验归属与内容
+    pub voter_weight_record: Option<UncheckedAccount<'info>>,
+
+    /// 押金以治理代币支付时，提案人支付押金的关联代币账户
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program
+    )]
+    pub proposer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 押金以治理代币支付时，ProposalDeposit 名下存放押金的关联代币账户
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = proposal_deposit,
+        associated_token::token_program = token_program
+    )]
+    pub deposit_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>
 }
 
 
 impl<'info> CreateProposal<'info> {
     pub fn process(
         &mut self,
+        bump_proposal_deposit: u8,
         name: String, 
         description_link: String,
+        vote_type: VoteType,
+        options: Vec<String>,
     ) -> Result<()> {
-        self.governance.resolve_vote_threshold()?;
+        self.governance.resolve_vote_threshold(&self.realm, &self.mint.key())?;
 
         // 基本参数校验
-        require!(name.as_bytes().len() <= 50, GovernanceError::NameTooLong);
-        require!(description_link.as_bytes().len() <= 255, GovernanceError::LinkTooLong);
+        require!(name.len() <= 50, GovernanceError::NameTooLong);
+        require!(description_link.len() <= 255, GovernanceError::LinkTooLong);
+        Proposal::assert_valid_options(&vote_type, &options)?;
         
-        if self.token_owner_record.governing_token_deposit_amount 
-            < self.governance.config.min_community_weight_to_create_proposal 
+        let voter_weight = self.realm_config_account.resolve_voter_weight(
+            &self.realm,
+            &self.token_owner_record,
+            self.voter_weight_record.as_ref().map(|record| record.as_ref()),
+            VoterWeightAction::CreateProposal,
+            &self.governance.key()
+        )?;
+
+        if voter_weight
+            < self.governance.get_min_weight_to_create_proposal(&self.realm, &self.mint.key())
         {
             return err!(GovernanceError::InsufficientVotingPower);
         }
 
+        // 押金按创建前的活跃提案数量计算
+        let proposal_deposit_amount = self.governance.get_proposal_deposit_amount();
+
         self.token_owner_record.outstanding_proposal_count = self.token_owner_record
                 .outstanding_proposal_count
                 .checked_add(1)
@@ -119,29 +182,86 @@ impl<'info> CreateProposal<'info> {
         proposal.account_type = GovernanceAccountType::Proposal;
         proposal.governance = self.governance.key();
         proposal.token_owner_record = self.token_owner_record.key();
+        proposal.governing_token_mint = self.mint.key();
         proposal.state = ProposalState::Draft;
+        proposal.draft_at = Clock::get()?.unix_timestamp.try_into()?;
+        proposal.vote_type = vote_type;
+        proposal.options = options
+            .into_iter()
+            .map(|label| ProposalOption {
+                label,
+                vote_weight: 0,
+                vote_result: OptionVoteResult::None,
+                transactions_count: 0,
+                transactions_executed_count: 0,
+                last_transaction_executed_at: None,
+            })
+            .collect();
         proposal.name = name;
         proposal.description_link = description_link;
 
-        let proposal_deposit_amount = self.governance.get_proposal_deposit_amount();
+        let deposit_type = self.governance.config.proposal_deposit_type.clone();
 
         let proposal_deposit = &mut self.proposal_deposit;
         proposal_deposit.account_type = GovernanceAccountType::ProposalDeposit;
         proposal_deposit.proposal = self.proposal.key();
         proposal_deposit.deposit_payer = self.authority.key();
+        proposal_deposit.deposit_type = deposit_type.clone();
+        proposal_deposit.amount = proposal_deposit_amount;
+        proposal_deposit.bump = bump_proposal_deposit;
 
-        // 将押金 lamports 转入 proposal_deposit 账户（在 rent_exempt 之外再锁定押金）
         if proposal_deposit_amount > 0 {
-            let cpi_ctx = CpiContext::new(
-                self.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: self.authority.to_account_info(),
-                    to: self.proposal_deposit.to_account_info(),
+            match deposit_type {
+                // 将押金 lamports 转入 proposal_deposit 账户（在 rent_exempt 之外再锁定押金）
+                ProposalDepositType::Sol => {
+                    let cpi_ctx = CpiContext::new(
+                        self.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: self.authority.to_account_info(),
+                            to: self.proposal_deposit.to_account_info(),
+                        },
+                    );
+                    system_program::transfer(cpi_ctx, proposal_deposit_amount)?;
+                },
+                // 将治理代币转入 proposal_deposit 名下的关联代币账户
+                ProposalDepositType::GoverningTokens => {
+                    let (
+                        Some(proposer_token_account),
+                        Some(deposit_token_account),
+                        Some(token_program)
+                    ) = (
+                        self.proposer_token_account.as_ref(),
+                        self.deposit_token_account.as_ref(),
+                        self.token_program.as_ref()
+                    ) else {
+                        return err!(GovernanceError::MissingProposalDepositTokenAccount);
+                    };
+
+                    transfer_checked(CpiContext::new(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: proposer_token_account.to_account_info(),
+                            mint: self.mint.to_account_info(),
+                            to: deposit_token_account.to_account_info(),
+                            authority: self.authority.to_account_info()
+                        }
+                        ),
+                        proposal_deposit_amount,
+                        self.mint.decimals
+                    )?;
                 },
-            );
-            system_program::transfer(cpi_ctx, proposal_deposit_amount)?;
+            }
         }
-        
+
+        emit!(ProposalCreated {
+            proposal: self.proposal.key(),
+            governance: self.governance.key(),
+            token_owner_record: self.token_owner_record.key(),
+            governing_token_mint: self.mint.key(),
+            proposer: self.authority.key(),
+            created_at: Clock::get()?.unix_timestamp,
+        });
+
         Ok(())
     }
 }
@@ -2,7 +2,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{error::GovernanceError, state::{GovernanceAccountType, GoverningTokenConfig, Realm, RealmConfig, RealmConfigAccount, Reserved110}, MintMaxVoterWeightSource};
+use crate::error::GovernanceError;
+use crate::events::RealmCreated;
+use crate::state::{GovernanceAccountType, GoverningTokenConfig, Realm, RealmConfig, RealmConfigAccount, Reserved110};
 
 
 #[derive(Accounts)]
@@ -51,6 +53,24 @@ pub struct CreateRealm<'info> {
     )]
     pub community_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    pub council_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// 与社区代币托管账户相同的种子结构 [mint, realm, seed]；
+    /// council_mint 是可选账户，通过 to_account_infos 取地址，未提供时本账户也不会初始化
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            council_mint.to_account_infos().first().map(|info| info.key()).unwrap_or_default().as_ref(),
+            realm.key().as_ref(),
+            RealmConfigAccount::COUNCIL_TOKEN_SEEDS
+        ],
+        bump,
+        token::mint = council_mint,
+        token::authority = realm
+    )]
+    pub council_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>
 }
@@ -62,19 +82,37 @@ impl<'info> CreateRealm<'info> {
         id: u64,
         name: String,
         realm_config: RealmConfig,
-        governing_token_config: GoverningTokenConfig
+        governing_token_config: GoverningTokenConfig,
+        council_token_config: GoverningTokenConfig
     ) -> Result<()> {
-        match realm_config.community_mint_max_voter_weight_source {
-            MintMaxVoterWeightSource::SupplyFraction(_fraction) => {},
-            MintMaxVoterWeightSource::Absolute(amount) => {
-                require!(amount > 0, GovernanceError::InvalidMintMaxVoterWeightSource);
-            }
+        require!(name.len() <= Realm::MAX_NAME_LEN, GovernanceError::NameTooLong);
+
+        realm_config.assert_is_valid()?;
+        governing_token_config.assert_is_valid()?;
+        council_token_config.assert_is_valid()?;
+
+        // 理事会代币 mint 与托管账户必须同时提供，否则理事会代币无处托管
+        require!(
+            self.council_mint.is_some() == self.council_token_account.is_some(),
+            GovernanceError::InvalidCouncilTokenAccount
+        );
+
+        if let Some(council_mint) = &self.council_mint {
+            require_keys_neq!(
+                council_mint.key(),
+                self.mint.key(),
+                GovernanceError::InvalidCouncilMint
+            );
         }
-        
+
         let realm_config_account = &mut self.realm_config_account;
         realm_config_account.account_type = GovernanceAccountType::RealmConfig;
         realm_config_account.realm = self.realm.key();
         realm_config_account.community_token_config = governing_token_config;
+        realm_config_account.council_token_config = match self.council_mint {
+            Some(_) => council_token_config,
+            None => GoverningTokenConfig::default(),
+        };
         realm_config_account.reserved = Reserved110::default();
 
         let realm = &mut self.realm;
@@ -84,7 +122,17 @@ impl<'info> CreateRealm<'info> {
         realm.config = realm_config;
         realm.community_mint = self.mint.key();
         realm.community_token_account = self.community_token_account.key();
-        realm.authority = self.authority.key();
+        realm.council_mint = self.council_mint.as_ref().map(|mint| mint.key());
+        realm.council_token_account = self.council_token_account.as_ref().map(|account| account.key());
+        realm.authority = Some(self.authority.key());
+
+        emit!(RealmCreated {
+            realm: realm.key(),
+            community_mint: realm.community_mint,
+            council_mint: realm.council_mint,
+            authority: self.authority.key(),
+            created_at: Clock::get()?.unix_timestamp,
+        });
 
         Ok(())
     }
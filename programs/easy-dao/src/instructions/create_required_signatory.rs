@@ -12,7 +12,8 @@ pub struct CreateRequiredSignatory<'info> {
     pub signatory: SystemAccount<'info>,
 
     #[account(
-        has_one = authority @ GovernanceError::UnauthorizedRealmAuthority
+        constraint = realm.is_realm_authority(&authority.key())
+            @ GovernanceError::UnauthorizedRealmAuthority
     )]
     pub realm: Account<'info, Realm>,
 
@@ -45,6 +46,7 @@ impl<'info> CreateRequiredSignatory<'info> {
         required_signatory.account_type = GovernanceAccountType::RequiredSignatory;
         required_signatory.governance = self.governance.key();
         required_signatory.signatory = self.signatory.key();
+        required_signatory.rent_payer = self.authority.key();
 
         self.governance.required_signatories_count = self.governance
             .required_signatories_count
@@ -19,8 +19,8 @@ pub struct CreateTokenOwnerRecord<'info> {
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
-        constraint = realm.community_mint == mint.key() 
-            @ GovernanceError::InvalidCommunityMint
+        constraint = realm.is_governing_token_mint(&mint.key())
+            @ GovernanceError::InvalidGoverningTokenMint
     )]
     pub realm: Account<'info, Realm>,
 
@@ -55,6 +55,7 @@ impl<'info> CreateTokenOwnerRecord<'info> {
         token_owner_record.outstanding_proposal_count = 0;
         token_owner_record.version = TokenOwnerRecord::TOKEN_OWNER_RECORD_LAYOUT_VERSION;
         token_owner_record.locks = vec![];
+        token_owner_record.rent_payer = self.user.key();
 
         Ok(())
     }
@@ -12,7 +12,7 @@ use anchor_spl::{
     }
 };
 
-use crate::{error::GovernanceError, Realm, RealmConfigAccount, TokenOwnerRecord};
+use crate::{error::GovernanceError, events::GoverningTokensDeposited, GoverningTokenType, Realm, RealmConfigAccount, TokenOwnerRecord};
 
 
 #[derive(Accounts)]
@@ -22,22 +22,29 @@ pub struct DepositGoverningTokens<'info> {
     pub realm: Account<'info, Realm>,
 
     #[account(
-        address = realm.community_mint
+        seeds = [
+            realm.id.to_le_bytes().as_ref(),
+            RealmConfigAccount::REALM_CONFIG_SEEDS
+        ],
+        bump,
+        constraint = realm_config_account.realm == realm.key()
+            @ GovernanceError::InvalidRealmConfigAccount
+    )]
+    pub realm_config_account: Account<'info, RealmConfigAccount>,
+
+    #[account(
+        constraint = realm.is_governing_token_mint(&mint.key())
+            @ GovernanceError::InvalidGoverningTokenMint
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
-        seeds = [
-            mint.key().as_ref(),
-            realm.key().as_ref(),
-            RealmConfigAccount::COMMUNITY_TOKEN_SEEDS
-        ],
-        bump,
+        address = realm.get_governing_token_holding(&mint.key()),
         token::mint = mint,
         token::authority = realm
     )]
-    pub community_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub governing_token_holding: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
@@ -70,13 +77,18 @@ impl<'info> DepositGoverningTokens<'info> {
     pub fn process(&mut self, amount: u64) -> Result<()> {
         require!(amount > 0, GovernanceError::InvalidDepositAmount);
 
+        let token_config = self.realm_config_account.get_token_config(&self.realm, &self.mint.key());
+        if let GoverningTokenType::Dormant = token_config.token_type {
+            return err!(GovernanceError::CannotDepositDormantTokens);
+        }
+
         // 转账
         transfer_checked(CpiContext::new(
             self.token_program.to_account_info(), 
             TransferChecked { 
                 from: self.user_token_account.to_account_info(), 
                 mint: self.mint.to_account_info(), 
-                to: self.community_token_account.to_account_info(), 
+                to: self.governing_token_holding.to_account_info(), 
                 authority: self.user.to_account_info() 
             }
             ), 
@@ -90,6 +102,16 @@ impl<'info> DepositGoverningTokens<'info> {
             .checked_add(amount)
             .ok_or(error!(GovernanceError::Overflow))?;
 
+        emit!(GoverningTokensDeposited {
+            realm: self.realm.key(),
+            governing_token_mint: self.mint.key(),
+            governing_token_owner: self.token_owner_record.governing_token_owner,
+            token_owner_record: self.token_owner_record.key(),
+            amount,
+            governing_token_deposit_amount: self.token_owner_record.governing_token_deposit_amount,
+            deposited_at: Clock::get()?.unix_timestamp,
+        });
+
         Ok(())
     }
 }
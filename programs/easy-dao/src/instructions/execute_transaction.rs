@@ -1,9 +1,10 @@
 use anchor_lang::{
     prelude::*, 
-    solana_program::{instruction::Instruction, program::invoke}
+    solana_program::{instruction::Instruction, program::invoke_signed}
 };
 use crate::{
     error::GovernanceError, 
+    events::TransactionExecuted,
     ExecutionStatus, 
     Governance, 
     Proposal, 
@@ -33,56 +34,116 @@ pub struct ExecuteTransaction<'info> {
 pub fn process_execute_transaction(
     ctx: Context<ExecuteTransaction>
 ) -> Result<()> {
+    let governance = &ctx.accounts.governance;
     let proposal = &mut ctx.accounts.proposal;
     let proposal_transaction = &mut ctx.accounts.proposal_transaction;
 
-    require!(
-        proposal.state == ProposalState::Executing, 
-        GovernanceError::InvalidStateCannotExecuteTransaction
-    );
+    let now: u64 = Clock::get()?.unix_timestamp.try_into()?;
 
-    if proposal_transaction.executed_at.is_some() {
-        return err!(GovernanceError::TransactionAlreadyExecuted);
+    // 有交易被标记失败后，提案只能在重试期限内继续执行（含重试失败的交易），重试时长为 0 时不允许
+    if proposal.state == ProposalState::ExecutionFailed {
+        let failed_at = proposal.closed_at
+            .ok_or(GovernanceError::InvalidStateCannotExecuteTransaction)?;
+        require!(
+            now < failed_at.saturating_add(governance.config.transaction_retry_time as u64),
+            GovernanceError::TransactionRetryTimeExpired
+        );
     }
 
-    // 执行指令
-    let ix = Instruction {
-        program_id: proposal_transaction.instruction.program_id,
-        accounts: proposal_transaction
-            .instruction
-            .accounts
-            .iter().map(|m| AccountMeta {
-                pubkey: m.pubkey,
-                is_signer: m.is_signer,
-                is_writable: m.is_writable,
-            })
-            .collect(),
-        data: proposal_transaction.instruction.data.clone(),
-    };
+    proposal.assert_can_execute_transaction(proposal_transaction, now)?;
+
+    // 组装全部指令，remaining_accounts 按指令顺序依次提供：目标程序账户，随后是该指令的账户
+    let instructions: Vec<Instruction> = proposal_transaction
+        .instructions
+        .iter()
+        .map(|instruction| Instruction {
+            program_id: instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter().map(|m| AccountMeta {
+                    pubkey: m.pubkey,
+                    is_signer: m.is_signer,
+                    is_writable: m.is_writable,
+                })
+                .collect(),
+            data: instruction.data.clone(),
+        })
+        .collect();
 
     require!(
-        ctx.remaining_accounts.len() == ix.accounts.len(),
+        ctx.remaining_accounts.len() == instructions.iter().map(|ix| ix.accounts.len() + 1).sum::<usize>(),
         GovernanceError::InvalidInstructionAccounts,
     );
 
-    // 先尝试执行指令，记录成功或失败
-    let result = invoke(&ix, ctx.remaining_accounts);
-    let now = Clock::get()?.unix_timestamp.try_into()?;
+    // 指令中被标记为签名者的 Governance / 原生金库 PDA 由本程序代签
+    let governance_key = governance.key();
+    let (native_treasury, treasury_bump) = Pubkey::find_program_address(
+        &[Governance::NATIVE_TREASURY_SEED, governance_key.as_ref()],
+        ctx.program_id
+    );
+    let treasury_bump_seed = [treasury_bump];
+    let governance_seeds = governance.get_signer_seeds();
+    let treasury_seeds: [&[u8]; 3] = [
+        Governance::NATIVE_TREASURY_SEED,
+        governance_key.as_ref(),
+        &treasury_bump_seed
+    ];
 
-    match result {
-        Ok(_) => {
-            proposal_transaction.execution_status = ExecutionStatus::Success;
+    // 按顺序执行全部指令；任一指令失败会使整笔交易回滚，失败需通过 flag_transaction_error 记录
+    let mut account_infos = ctx.remaining_accounts;
+    instructions.iter().try_for_each(|ix| {
+        let (ix_account_infos, rest) = account_infos.split_at(ix.accounts.len() + 1);
+        account_infos = rest;
+
+        // 目标程序账户只用于 CPI，不写入指令的账户列表
+        let (program_info, ix_account_infos) = ix_account_infos.split_first()
+            .ok_or(GovernanceError::InvalidInstructionAccounts)?;
+        require_keys_eq!(
+            *program_info.key,
+            ix.program_id,
+            GovernanceError::InvalidInstructionProgram
+        );
+
+        let mut signers_seeds: Vec<&[&[u8]]> = vec![];
+        if ix.accounts.iter().any(|m| m.is_signer && m.pubkey == governance_key) {
+            signers_seeds.push(&governance_seeds);
         }
-        Err(e) => {
-            proposal_transaction.execution_status = ExecutionStatus::Error;
-            msg!("Transaction execution failed: {}", e);
+        if ix.accounts.iter().any(|m| m.is_signer && m.pubkey == native_treasury) {
+            signers_seeds.push(&treasury_seeds);
         }
-    }
 
+        let mut cpi_account_infos = ix_account_infos.to_vec();
+        cpi_account_infos.push(program_info.clone());
+
+        invoke_signed(ix, &cpi_account_infos, &signers_seeds).map_err(Into::into)
+    })?;
+
+    proposal_transaction.execution_status = ExecutionStatus::Success;
     proposal_transaction.executed_at = Some(now);
-    
-    proposal.state = ProposalState::Completed;
-    proposal.closed_at = Some(now);
-   
+
+    let option = &mut proposal.options[proposal_transaction.option_index as usize];
+    option.transactions_executed_count = option.transactions_executed_count
+        .checked_add(1)
+        .ok_or(GovernanceError::Overflow)?;
+    option.last_transaction_executed_at = Some(now);
+
+    if proposal.all_transactions_executed() {
+        proposal.state = ProposalState::Completed;
+        proposal.closed_at = Some(now);
+    } else if proposal_transaction.error_flagged_at.is_some() {
+        // 失败交易重试成功，提案恢复执行，后续交易继续按顺序执行
+        proposal.state = ProposalState::Executing;
+        proposal.closed_at = None;
+    }
+
+    emit!(TransactionExecuted {
+        proposal: proposal.key(),
+        proposal_transaction: proposal_transaction.key(),
+        option_index: proposal_transaction.option_index,
+        transaction_index: proposal_transaction.transaction_index,
+        execution_status: proposal_transaction.execution_status.clone(),
+        executed_at: now.try_into()?,
+    });
+
     Ok(())
 }
@@ -0,0 +1,56 @@
+//! 草稿提案过期指令
+use anchor_lang::prelude::*;
+
+use crate::{error::GovernanceError, events::ProposalExpired, Governance, Proposal, ProposalState, TokenOwnerRecord};
+
+
+#[derive(Accounts)]
+pub struct ExpireDraftProposal<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        has_one = governance @ GovernanceError::InvalidGovernanceForAccount,
+        has_one = token_owner_record
+            @ GovernanceError::InvalidProposalTokenOwnerRecord
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+}
+
+
+impl<'info> ExpireDraftProposal<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        require!(
+            matches!(self.proposal.state, ProposalState::Draft | ProposalState::SigningOff),
+            GovernanceError::InvalidProposalState
+        );
+
+        let now: u64 = Clock::get()?.unix_timestamp.try_into()?;
+        require!(
+            self.proposal.is_draft_expired(&self.governance.config, now),
+            GovernanceError::ProposalDraftNotExpired
+        );
+
+        // 过期视为放弃而非否决，押金可照常退还
+        self.proposal.state = ProposalState::Expired;
+        self.proposal.closed_at = Some(now);
+
+        self.token_owner_record.decrease_outstanding_proposal_count()?;
+        self.governance.active_proposal_count = self.governance.active_proposal_count
+            .checked_sub(1)
+            .ok_or(error!(GovernanceError::Overflow))?;
+
+        emit!(ProposalExpired {
+            proposal: self.proposal.key(),
+            governance: self.governance.key(),
+            draft_at: self.proposal.draft_at.try_into()?,
+            expired_at: now.try_into()?,
+        });
+
+        Ok(())
+    }
+}
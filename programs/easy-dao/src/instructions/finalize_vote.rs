@@ -3,7 +3,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
-use crate::{error::GovernanceError, Governance, Proposal, ProposalState, Realm, TokenOwnerRecord};
+use crate::{error::GovernanceError, events::VoteFinalized, Governance, Proposal, Realm, RealmConfigAccount, TokenOwnerRecord};
 
 
 #[derive(Accounts)]
@@ -13,7 +13,18 @@ pub struct FinalizeVote<'info> {
     pub realm: Account<'info, Realm>,
 
     #[account(
-       address = realm.community_mint
+        seeds = [
+            realm.id.to_le_bytes().as_ref(),
+            RealmConfigAccount::REALM_CONFIG_SEEDS
+        ],
+        bump,
+        constraint = realm_config_account.realm == realm.key()
+            @ GovernanceError::InvalidRealmConfigAccount
+    )]
+    pub realm_config_account: Box<Account<'info, RealmConfigAccount>>,
+
+    #[account(
+       address = proposal.governing_token_mint
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
@@ -45,31 +56,41 @@ pub struct FinalizeVote<'info> {
             @ GovernanceError::InvalidTokenOwnerRecordOwner
     )]
     pub token_owner_record: Account<'info, TokenOwnerRecord>,
+
+    /// CHECK: 最大票权插件的 MaxVoterWeightRecord，未配置插件时不传，在指令中校验归属与内容
+    pub max_voter_weight_record: Option<UncheckedAccount<'info>>,
 }
 
 
 impl<'info> FinalizeVote<'info> {
     pub fn process(&mut self) -> Result<()> {
         self.proposal.assert_can_finalize_vote(&self.governance.config)?;
+
+        let vote_threshold = self.governance.resolve_vote_threshold(&self.realm, &self.mint.key())?;
         
-        let max_voter_weight = self.proposal.get_max_voter_weight_from_mint_supply(
-            self.mint.supply,
-            self.mint.decimals,
-            self.realm.config.community_mint_max_voter_weight_source.clone()
-        ).ok_or(error!(GovernanceError::Overflow))?;
-
-
-        let finalized = self.proposal.maybe_finalize_vote(
-            max_voter_weight, 
-            self.governance.config.community_vote_threshold.clone()
-        )?; 
-
-        if !finalized {
-            self.proposal.state = ProposalState::Defeated;
-            let now = Clock::get()?.unix_timestamp.try_into()?;
-            self.proposal.voting_completed_at = Some(now);
-            self.proposal.vote_threshold = Some(self.governance.config.community_vote_threshold.clone());
-        }
+        let max_voter_weight = match self.realm_config_account.resolve_max_voter_weight_from_addin(
+            &self.realm,
+            &self.mint.key(),
+            self.max_voter_weight_record.as_ref().map(|record| record.as_ref())
+        )? {
+            Some(max_voter_weight) => max_voter_weight,
+            None => self.proposal.get_max_voter_weight_from_mint_supply(
+                self.mint.supply,
+                self.realm.get_mint_max_voter_weight_source(&self.mint.key())
+            ).ok_or(error!(GovernanceError::Overflow))?,
+        };
+
+        self.proposal.finalize_vote(max_voter_weight, vote_threshold)?;
+
+        emit!(VoteFinalized {
+            proposal: self.proposal.key(),
+            state: self.proposal.state.clone(),
+            tipped: false,
+            max_voter_weight,
+            voting_completed_at: self.proposal.voting_completed_at
+                .map(i64::try_from)
+                .transpose()?,
+        });
         
         self.token_owner_record.decrease_outstanding_proposal_count()?;
         self.governance.active_proposal_count = self.governance.active_proposal_count
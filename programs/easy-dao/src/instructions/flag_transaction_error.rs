@@ -0,0 +1,78 @@
+//! 标记提案交易执行失败指令
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceError,
+    events::TransactionErrorFlagged,
+    ExecutionStatus,
+    Governance,
+    Proposal,
+    ProposalState,
+    ProposalTransaction,
+    TokenOwnerRecord
+};
+
+
+#[derive(Accounts)]
+pub struct FlagTransactionError<'info> {
+    /// 只有提案发起人 TokenOwnerRecord 的拥有者或其委托人可以标记失败
+    pub authority: Signer<'info>,
+
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        constraint = token_owner_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign
+    )]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+
+    #[account(
+        mut,
+        has_one = governance @ GovernanceError::InvalidGovernanceForAccount,
+        has_one = token_owner_record
+            @ GovernanceError::InvalidProposalTokenOwnerRecord
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        has_one = proposal @ GovernanceError::InvalidProposalForTransaction,
+    )]
+    pub proposal_transaction: Account<'info, ProposalTransaction>,
+}
+
+
+impl<'info> FlagTransactionError<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let now: u64 = Clock::get()?.unix_timestamp.try_into()?;
+
+        require!(
+            self.proposal_transaction.error_flagged_at.is_none(),
+            GovernanceError::TransactionAlreadyFlagged
+        );
+
+        // 只有轮到执行、且可执行后经过执行窗口仍未执行的交易才能被标记失败
+        self.proposal.assert_can_flag_transaction_error(&self.proposal_transaction, now)?;
+
+        self.proposal_transaction.execution_status = ExecutionStatus::Error;
+        self.proposal_transaction.error_flagged_at = Some(now);
+
+        // 失败后提案视为结束，押金可退还；重试期限内仍可通过 execute_transaction 重新执行，
+        // 重试成功后提案恢复为 Executing
+        self.proposal.state = ProposalState::ExecutionFailed;
+        self.proposal.closed_at = Some(now);
+
+        emit!(TransactionErrorFlagged {
+            proposal: self.proposal.key(),
+            proposal_transaction: self.proposal_transaction.key(),
+            option_index: self.proposal_transaction.option_index,
+            transaction_index: self.proposal_transaction.transaction_index,
+            retry_deadline: now
+                .saturating_add(self.governance.config.transaction_retry_time as u64)
+                .try_into()?,
+            flagged_at: now.try_into()?,
+        });
+
+        Ok(())
+    }
+}
@@ -1,16 +1,21 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::{
+    close_account,
+    CloseAccount,
+    Mint,
+    TokenAccount,
+    TokenInterface,
+    transfer_checked,
+    TransferChecked
+};
 
-use crate::{error::GovernanceError, Proposal, ProposalDeposit, Realm, TokenOwnerRecord};
+use crate::{error::GovernanceError, events::ProposalDepositRefunded, Governance, Proposal, ProposalDeposit, ProposalDepositType, Realm, TokenOwnerRecord};
 
 
 #[derive(Accounts)]
 pub struct RefundProposalDeposit<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
-
     pub realm: Account<'info, Realm>,
-    #[account(address = realm.community_mint)]
+    #[account(address = proposal.governing_token_mint)]
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
@@ -19,31 +24,79 @@ pub struct RefundProposalDeposit<'info> {
             TokenOwnerRecord::RECORD_SEED,
             realm.key().as_ref(),
             mint.key().as_ref(),
-            authority.key().as_ref(),
+            token_owner_record.governing_token_owner.as_ref(),
         ],
         bump,
-        has_one = realm @ GovernanceError::InvalidTokenOwnerRecordRealm,
-        constraint = token_owner_record.governing_token_owner == authority.key() 
-            @ GovernanceError::InvalidTokenOwnerRecordOwner
+        has_one = realm @ GovernanceError::InvalidTokenOwnerRecordRealm
     )]
     pub token_owner_record: Account<'info, TokenOwnerRecord>,
 
     #[account(
-        has_one = token_owner_record 
+        has_one = realm @ GovernanceError::InvalidGovernanceRealm
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        has_one = governance @ GovernanceError::InvalidGovernanceForAccount,
+        has_one = token_owner_record
             @ GovernanceError::InvalidProposalTokenOwnerRecord
     )]
     pub proposal: Account<'info, Proposal>,
 
     #[account(
         mut,
-        has_one = proposal @ GovernanceError::InvalidProposalDepositProposal,
-        constraint = proposal_deposit.deposit_payer == authority.key() 
-            @ GovernanceError::InvalidProposalDepositDepositPayer,
-        close = authority
+        has_one = proposal @ GovernanceError::InvalidProposalDepositProposal
     )]
     pub proposal_deposit: Account<'info, ProposalDeposit>,
-    
+
+    /// 押金支付人，押金未被罚没时退还给它；账户租金总是退还给它
+    #[account(
+        mut,
+        address = proposal_deposit.deposit_payer
+            @ GovernanceError::InvalidProposalDepositDepositPayer
+    )]
+    pub deposit_payer: SystemAccount<'info>,
+
+    /// 押金被罚没时接收押金的治理原生金库
+    #[account(
+        mut,
+        seeds = [
+            Governance::NATIVE_TREASURY_SEED,
+            governance.key().as_ref()
+        ],
+        bump
+    )]
+    pub native_treasury: Option<SystemAccount<'info>>,
+
+    /// 押金以治理代币支付时，ProposalDeposit 名下存放押金的关联代币账户
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = proposal_deposit,
+        associated_token::token_program = token_program
+    )]
+    pub deposit_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 押金以治理代币支付且未被罚没时，接收退还押金的关联代币账户
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = deposit_payer,
+        associated_token::token_program = token_program
+    )]
+    pub deposit_payer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 押金以治理代币支付且被罚没时，接收押金的金库关联代币账户
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = native_treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 impl<'info> RefundProposalDeposit<'info> {
@@ -52,6 +105,106 @@ impl<'info> RefundProposalDeposit<'info> {
             return err!(GovernanceError::ProposalStillInVoting);
         }
 
+        let slashed = self.proposal.is_deposit_slashed();
+
+        let amount = match self.proposal_deposit.deposit_type {
+            ProposalDepositType::Sol => {
+                let amount = self.proposal_deposit.amount;
+                if slashed && amount > 0 {
+                    self.slash_lamports(amount)?;
+                }
+                amount
+            },
+            // 代币押金先转出，再关闭代币账户并退还其租金
+            ProposalDepositType::GoverningTokens => self.refund_governing_tokens(slashed)?,
+        };
+
+        // 关闭押金账户，剩余 lamports 退还给押金支付人
+        self.proposal_deposit.close(self.deposit_payer.to_account_info())?;
+
+        emit!(ProposalDepositRefunded {
+            proposal: self.proposal.key(),
+            proposal_deposit: self.proposal_deposit.key(),
+            deposit_payer: self.proposal_deposit.deposit_payer,
+            deposit_type: self.proposal_deposit.deposit_type.clone(),
+            amount,
+            slashed,
+            refunded_at: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// 罚没的 lamports 转入治理原生金库；金库尚未创建或转入后仍低于免租金额时，
+    /// 连同押金账户的租金一并转入，避免金库租金不足导致转账失败、押金永远无法退还
+    fn slash_lamports(&self, amount: u64) -> Result<()> {
+        let native_treasury = self.native_treasury
+            .as_ref()
+            .ok_or(error!(GovernanceError::MissingNativeTreasury))?;
+
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(0);
+        let amount = if native_treasury.lamports().saturating_add(amount) < rent_exempt_lamports {
+            self.proposal_deposit.get_lamports()
+        } else {
+            amount
+        };
+
+        self.proposal_deposit.sub_lamports(amount)?;
+        native_treasury.add_lamports(amount)?;
+
         Ok(())
     }
+
+    /// 转出治理代币押金（罚没时转入金库，否则退还支付人），返回转出的代币数量
+    fn refund_governing_tokens(&self, slashed: bool) -> Result<u64> {
+        let (Some(deposit_token_account), Some(token_program)) = (
+            self.deposit_token_account.as_ref(),
+            self.token_program.as_ref()
+        ) else {
+            // 押金为 0 时创建提案不会生成代币账户
+            require!(
+                self.proposal_deposit.amount == 0,
+                GovernanceError::MissingProposalDepositTokenAccount
+            );
+            return Ok(0);
+        };
+
+        let signer_seeds = self.proposal_deposit.get_signer_seeds();
+        let signer = &[&signer_seeds[..]];
+        let amount = deposit_token_account.amount;
+
+        if amount > 0 {
+            let destination_token_account = if slashed {
+                self.treasury_token_account.as_ref()
+            } else {
+                self.deposit_payer_token_account.as_ref()
+            }.ok_or(error!(GovernanceError::MissingProposalDepositTokenAccount))?;
+
+            transfer_checked(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: deposit_token_account.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: destination_token_account.to_account_info(),
+                    authority: self.proposal_deposit.to_account_info()
+                },
+                signer
+                ),
+                amount,
+                self.mint.decimals
+            )?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: deposit_token_account.to_account_info(),
+                destination: self.deposit_payer.to_account_info(),
+                authority: self.proposal_deposit.to_account_info()
+            },
+            signer
+        ))?;
+
+        Ok(amount)
+    }
 }
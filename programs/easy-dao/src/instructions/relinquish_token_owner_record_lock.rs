@@ -0,0 +1,22 @@
+//! 解除用户治理账户的锁
+use anchor_lang::prelude::*;
+
+use crate::TokenOwnerRecord;
+
+
+#[derive(Accounts)]
+pub struct RelinquishTokenOwnerRecordLock<'info> {
+    /// 设置该锁的权限人
+    pub lock_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+}
+
+
+impl<'info> RelinquishTokenOwnerRecordLock<'info> {
+    pub fn process(&mut self, lock_id: u8) -> Result<()> {
+        // 只能解除自己设置的锁，权限人被移出配置后仍可解除
+        self.token_owner_record.remove_lock(lock_id, &self.lock_authority.key())
+    }
+}
@@ -2,7 +2,7 @@
 
 use anchor_lang::prelude::*;
 
-use crate::{error::GovernanceError, Governance, Proposal, ProposalState, Realm, TokenOwnerRecord, VoteRecord};
+use crate::{error::GovernanceError, events::VoteRelinquished, Governance, Proposal, ProposalState, Realm, TokenOwnerRecord, VoteRecord};
 
 #[derive(Accounts)]
 pub struct RelinquishVote<'info> {
@@ -18,8 +18,8 @@ pub struct RelinquishVote<'info> {
     #[account(
         mut,
         has_one = realm @GovernanceError::InvalidTokenOwnerRecordRealm,
-        constraint = token_owner_record.governing_token_owner == signer.key() 
-            @ GovernanceError::InvalidTokenOwnerRecordOwner
+        constraint = token_owner_record.is_token_owner_or_delegate(&signer.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign
     )]
     pub token_owner_record: Account<'info, TokenOwnerRecord>,
 
@@ -36,32 +36,60 @@ pub struct RelinquishVote<'info> {
             @ GovernanceError::InvalidTokenOwnerRecordOwner
     )]
     pub vote_record: Account<'info, VoteRecord>,
+
+    /// 投票时支付租金的账户，投票期间撤回关闭投票记录时退还给它
+    #[account(
+        mut,
+        address = vote_record.rent_payer @ GovernanceError::InvalidRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
 }
 
 
 impl<'info> RelinquishVote<'info> {
     pub fn process(&mut self) -> Result<()> {
-
-        require!(
-            matches!(
-                self.proposal.state, 
-                ProposalState::Completed 
-                    | ProposalState::Defeated
-            ), 
-            GovernanceError::ProposalStillInVoting
-        );
-
         require!(
             !self.vote_record.is_relinquished,
             GovernanceError::VoteAlreadyRelinquished
         );
-        
+
+        let in_voting = match self.proposal.state {
+            ProposalState::Voting => true,
+            // 投票已结束，执行中的提案也可撤回，否则交易未执行完前投票人的代币一直被锁定
+            ProposalState::Executing
+                | ProposalState::Completed
+                | ProposalState::Defeated
+                | ProposalState::Cancelled
+                | ProposalState::Vetoed
+                | ProposalState::ExecutionFailed => false,
+            _ => return err!(GovernanceError::ProposalStillInVoting),
+        };
+
         self.vote_record.is_relinquished = true;
         self.token_owner_record.unrelinquished_votes_count = self.token_owner_record
             .unrelinquished_votes_count
             .checked_sub(1)
             .ok_or(GovernanceError::Overflow)?;
-        
+
+        // 投票期间撤回：从计票中扣除并关闭投票记录，投票人可重新投票
+        if in_voting {
+            self.proposal.assert_can_relinquish_vote_in_voting(
+                &self.governance.config,
+                &self.vote_record.vote
+            )?;
+            self.proposal.remove_vote(&self.vote_record.vote, self.vote_record.vote_weight)?;
+            self.vote_record.close(self.rent_payer.to_account_info())?;
+        }
+
+        emit!(VoteRelinquished {
+            proposal: self.proposal.key(),
+            vote_record: self.vote_record.key(),
+            token_owner_record: self.token_owner_record.key(),
+            vote_weight: self.vote_record.vote_weight,
+            withdrawn_from_tally: in_voting,
+            relinquished_at: Clock::get()?.unix_timestamp,
+        });
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -10,7 +10,8 @@ pub struct RemoveRequiredSignatory<'info> {
     pub signatory: SystemAccount<'info>,
 
     #[account(
-        has_one = authority @ GovernanceError::UnauthorizedRealmAuthority
+        constraint = realm.is_realm_authority(&authority.key())
+            @ GovernanceError::UnauthorizedRealmAuthority
     )]
     pub realm: Account<'info, Realm>,
 
@@ -22,7 +23,7 @@ pub struct RemoveRequiredSignatory<'info> {
 
     #[account(
         mut,
-        close = authority,
+        close = rent_payer,
         seeds = [
             RequiredSignatory::REQUIRED_SIGNATORY_SEED,
             governance.key().as_ref(),
@@ -32,6 +33,13 @@ pub struct RemoveRequiredSignatory<'info> {
     )]
     pub required_signatory: Box<Account<'info, RequiredSignatory>>,
 
+    /// 创建必须签署人时支付租金的账户，关闭后租金退还给它
+    #[account(
+        mut,
+        address = required_signatory.rent_payer @ GovernanceError::InvalidRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+
     pub system_program: Program<'info, System>
 }
 
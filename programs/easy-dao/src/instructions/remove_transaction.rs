@@ -0,0 +1,63 @@
+//! 移除提案指令
+use anchor_lang::prelude::*;
+
+use crate::{error::GovernanceError, Proposal, ProposalState, ProposalTransaction, TokenOwnerRecord};
+
+
+#[derive(Accounts)]
+pub struct RemoveTransaction<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = token_owner_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign,
+    )]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+
+    #[account(
+        mut,
+        has_one = token_owner_record @ GovernanceError::InvalidTokenOwnerRecordOwner,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = proposal @ GovernanceError::InvalidProposalForTransaction,
+    )]
+    pub proposal_transaction: Account<'info, ProposalTransaction>,
+
+    /// 添加指令时支付租金的账户，关闭后租金退还给它
+    #[account(
+        mut,
+        address = proposal_transaction.rent_payer @ GovernanceError::InvalidRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+
+impl<'info> RemoveTransaction<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        require!(
+            self.proposal.state == ProposalState::Draft,
+            GovernanceError::InvalidProposalState
+        );
+
+        let option = self.proposal.options
+            .get_mut(self.proposal_transaction.option_index as usize)
+            .ok_or(GovernanceError::InvalidOptionIndex)?;
+
+        // 指令按序号派生地址，只允许移除最后一条，保证后续 add_transaction 的序号连续
+        let last_index = option.transactions_count
+            .checked_sub(1)
+            .ok_or(GovernanceError::Overflow)?;
+        require!(
+            self.proposal_transaction.transaction_index == last_index,
+            GovernanceError::CanOnlyRemoveLastTransaction
+        );
+
+        option.transactions_count = last_index;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+//! 更新治理配置指令
+use anchor_lang::prelude::*;
+
+use crate::{Governance, GovernanceConfig};
+
+
+#[derive(Accounts)]
+pub struct SetGovernanceConfig<'info> {
+    /// Governance PDA 必须签名，只能通过提案执行调用
+    #[account(mut, signer)]
+    pub governance: Account<'info, Governance>,
+}
+
+
+impl<'info> SetGovernanceConfig<'info> {
+    pub fn process(&mut self, config: GovernanceConfig) -> Result<()> {
+        Governance::assert_is_valid_governance_config(&config)?;
+
+        self.governance.config = config;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+//! 设置治理代理人指令
+use anchor_lang::prelude::*;
+
+use crate::{error::GovernanceError, TokenOwnerRecord};
+
+
+#[derive(Accounts)]
+pub struct SetGovernanceDelegate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = token_owner_record.is_token_owner_or_delegate(&authority.key())
+            @ GovernanceError::GoverningTokenOwnerOrDelegateMustSign
+    )]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+}
+
+
+impl<'info> SetGovernanceDelegate<'info> {
+    /// 传入 None 即清除代理人
+    pub fn process(&mut self, new_governance_delegate: Option<Pubkey>) -> Result<()> {
+        self.token_owner_record.governance_delegate = new_governance_delegate;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+//! 设置 Realm 治理权拥有者指令
+use anchor_lang::prelude::*;
+
+use crate::{error::GovernanceError, Governance, Realm, SetRealmAuthorityAction};
+
+
+#[derive(Accounts)]
+pub struct SetRealmAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = realm.is_realm_authority(&authority.key())
+            @ GovernanceError::UnauthorizedRealmAuthority
+    )]
+    pub realm: Account<'info, Realm>,
+
+    /// CHECK: 新的治理权拥有者，Remove 时可不传；SetChecked 时在指令中校验为 Governance 账户
+    pub new_realm_authority: Option<UncheckedAccount<'info>>,
+}
+
+
+impl<'info> SetRealmAuthority<'info> {
+    pub fn process(&mut self, action: SetRealmAuthorityAction) -> Result<()> {
+        let new_realm_authority = match action {
+            SetRealmAuthorityAction::Remove => None,
+            SetRealmAuthorityAction::SetUnchecked | SetRealmAuthorityAction::SetChecked => {
+                let new_realm_authority = self.new_realm_authority
+                    .as_ref()
+                    .ok_or(GovernanceError::NewRealmAuthorityRequired)?;
+
+                if action == SetRealmAuthorityAction::SetChecked {
+                    require_keys_eq!(
+                        *new_realm_authority.owner,
+                        crate::ID,
+                        GovernanceError::InvalidNewRealmAuthority
+                    );
+
+                    let data = new_realm_authority.try_borrow_data()?;
+                    let governance = Governance::try_deserialize(&mut &data[..])
+                        .map_err(|_| GovernanceError::InvalidNewRealmAuthority)?;
+                    require_keys_eq!(
+                        governance.realm,
+                        self.realm.key(),
+                        GovernanceError::InvalidNewRealmAuthority
+                    );
+                }
+
+                Some(new_realm_authority.key())
+            }
+        };
+
+        self.realm.authority = new_realm_authority;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+//! 更新 Realm 配置指令
+use anchor_lang::prelude::*;
+
+use crate::{error::GovernanceError, GoverningTokenConfig, Realm, RealmConfig, RealmConfigAccount};
+
+
+#[derive(Accounts)]
+pub struct SetRealmConfig<'info> {
+    /// Realm 治理权拥有者，移交给 Governance 后由提案执行签名
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = realm.is_realm_authority(&authority.key())
+            @ GovernanceError::UnauthorizedRealmAuthority
+    )]
+    pub realm: Account<'info, Realm>,
+
+    #[account(
+        mut,
+        seeds = [
+            realm.id.to_le_bytes().as_ref(),
+            RealmConfigAccount::REALM_CONFIG_SEEDS
+        ],
+        bump,
+        constraint = realm_config_account.realm == realm.key()
+            @ GovernanceError::InvalidRealmConfigAccount
+    )]
+    pub realm_config_account: Account<'info, RealmConfigAccount>,
+}
+
+
+impl<'info> SetRealmConfig<'info> {
+    pub fn process(
+        &mut self,
+        realm_config: RealmConfig,
+        community_token_config: GoverningTokenConfig,
+        council_token_config: GoverningTokenConfig
+    ) -> Result<()> {
+        realm_config.assert_is_valid()?;
+        community_token_config.assert_is_valid()?;
+        council_token_config.assert_is_valid()?;
+
+        let realm_config_account = &mut self.realm_config_account;
+        realm_config_account.community_token_config = community_token_config;
+        // 没有理事会代币时忽略传入的理事会配置
+        realm_config_account.council_token_config = match self.realm.council_mint {
+            Some(_) => council_token_config,
+            None => GoverningTokenConfig::default(),
+        };
+
+        self.realm.config = realm_config;
+
+        Ok(())
+    }
+}
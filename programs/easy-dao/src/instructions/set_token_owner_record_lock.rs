@@ -0,0 +1,50 @@
+//! 锁定用户治理账户
+use anchor_lang::prelude::*;
+
+use crate::{error::GovernanceError, Realm, RealmConfigAccount, TokenOwnerRecord, TokenOwnerRecordLock};
+
+
+#[derive(Accounts)]
+pub struct SetTokenOwnerRecordLock<'info> {
+    /// 锁定权限人，必须在 RealmConfig 对应代币配置的 lock_authorities 中
+    pub lock_authority: Signer<'info>,
+
+    pub realm: Account<'info, Realm>,
+
+    #[account(
+        seeds = [
+            realm.id.to_le_bytes().as_ref(),
+            RealmConfigAccount::REALM_CONFIG_SEEDS
+        ],
+        bump,
+        constraint = realm_config_account.realm == realm.key()
+            @ GovernanceError::InvalidRealmConfigAccount
+    )]
+    pub realm_config_account: Account<'info, RealmConfigAccount>,
+
+    #[account(
+        mut,
+        constraint = token_owner_record.realm == realm.key()
+            @ GovernanceError::InvalidTokenOwnerRecordRealm
+    )]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+}
+
+
+impl<'info> SetTokenOwnerRecordLock<'info> {
+    pub fn process(&mut self, lock_id: u8, expiry: u64) -> Result<()> {
+        let token_config = self.realm_config_account
+            .get_token_config(&self.realm, &self.token_owner_record.governing_token_mint);
+
+        require!(
+            token_config.lock_authorities.contains(&self.lock_authority.key()),
+            GovernanceError::InvalidLockAuthority
+        );
+
+        self.token_owner_record.upsert_lock(TokenOwnerRecordLock {
+            lock_id,
+            authority: self.lock_authority.key(),
+            expiry
+        })
+    }
+}
@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 
 use crate::{
     error::GovernanceError, 
+    events::{ProposalSignedOff, VotingStarted},
     Governance, 
     Proposal, 
     Realm, 
@@ -53,6 +54,14 @@ impl<'info> SignOffProposal<'info> {
             ),
             GovernanceError::InvalidProposalState
         );
+
+        require!(
+            !self.proposal.is_draft_expired(
+                &self.governance.config,
+                Clock::get()?.unix_timestamp.try_into()?
+            ),
+            GovernanceError::ProposalDraftExpired
+        );
         
         if self.proposal.signatories_count > 0 
             && self.proposal.signatories_count < self.governance.required_signatories_count 
@@ -87,9 +96,23 @@ impl<'info> SignOffProposal<'info> {
                 .ok_or(GovernanceError::Overflow)?;
         }
 
+        emit!(ProposalSignedOff {
+            proposal: self.proposal.key(),
+            signatory: self.signatory.key(),
+            signatories_signed_off_count: self.proposal.signatories_signed_off_count,
+            signed_off_at: Clock::get()?.unix_timestamp,
+        });
+
         if self.proposal.signatories_signed_off_count == self.proposal.signatories_count {
             self.proposal.voting_started_at = Clock::get()?.unix_timestamp.try_into()?;
             self.proposal.state = ProposalState::Voting;
+            self.proposal.deposit_slash_turnout_percentage = self.governance.config.deposit_slash_turnout_percentage;
+
+            emit!(VotingStarted {
+                proposal: self.proposal.key(),
+                governance: self.governance.key(),
+                voting_started_at: self.proposal.voting_started_at.try_into()?,
+            });
         }
 
         Ok(())
@@ -0,0 +1,144 @@
+//! 用户治理账户取出代币
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        Mint,
+        TokenAccount,
+        TokenInterface,
+        transfer_checked,
+        TransferChecked
+    }
+};
+
+use crate::{
+    error::GovernanceError,
+    events::GoverningTokensWithdrawn,
+    GoverningTokenType,
+    Realm,
+    RealmConfigAccount,
+    TokenOwnerRecord
+};
+
+
+#[derive(Accounts)]
+pub struct WithdrawGoverningTokens<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [
+            realm.id.to_le_bytes().as_ref(),
+            Realm::REALM_SEEDS
+        ],
+        bump
+    )]
+    pub realm: Account<'info, Realm>,
+
+    #[account(
+        seeds = [
+            realm.id.to_le_bytes().as_ref(),
+            RealmConfigAccount::REALM_CONFIG_SEEDS
+        ],
+        bump,
+        constraint = realm_config_account.realm == realm.key()
+            @ GovernanceError::InvalidRealmConfigAccount
+    )]
+    pub realm_config_account: Account<'info, RealmConfigAccount>,
+
+    #[account(
+        constraint = realm.is_governing_token_mint(&mint.key())
+            @ GovernanceError::InvalidGoverningTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        address = realm.get_governing_token_holding(&mint.key()),
+        token::mint = mint,
+        token::authority = realm
+    )]
+    pub governing_token_holding: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            TokenOwnerRecord::RECORD_SEED,
+            realm.key().as_ref(),
+            mint.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump,
+        constraint = token_owner_record.governing_token_owner == user.key()
+            @ GovernanceError::InvalidTokenOwnerRecordOwner
+    )]
+    pub token_owner_record: Account<'info, TokenOwnerRecord>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>
+}
+
+
+impl<'info> WithdrawGoverningTokens<'info> {
+    pub fn process(&mut self, bump_realm: u8, amount: u64) -> Result<()> {
+        require!(
+            amount > 0 && amount <= self.token_owner_record.governing_token_deposit_amount,
+            GovernanceError::InvalidWithdrawAmount
+        );
+
+        let token_config = self.realm_config_account.get_token_config(&self.realm, &self.mint.key());
+        if let GoverningTokenType::Membership = token_config.token_type {
+            return err!(GovernanceError::CannotWithdrawMembershipTokens);
+        }
+
+        self.token_owner_record.assert_can_withdraw_governing_tokens()?;
+
+        // 以 Realm PDA 签名转出
+        let id_bytes = self.realm.id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            id_bytes.as_ref(),
+            Realm::REALM_SEEDS,
+            &[bump_realm]
+        ]];
+
+        transfer_checked(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            TransferChecked {
+                from: self.governing_token_holding.to_account_info(),
+                mint: self.mint.to_account_info(),
+                to: self.user_token_account.to_account_info(),
+                authority: self.realm.to_account_info()
+            },
+            signer_seeds
+            ),
+            amount,
+            self.mint.decimals
+        )?;
+
+        // 变更用户治理账户
+        self.token_owner_record.governing_token_deposit_amount = self.token_owner_record
+            .governing_token_deposit_amount
+            .checked_sub(amount)
+            .ok_or(error!(GovernanceError::Overflow))?;
+
+        emit!(GoverningTokensWithdrawn {
+            realm: self.realm.key(),
+            governing_token_mint: self.mint.key(),
+            governing_token_owner: self.token_owner_record.governing_token_owner,
+            token_owner_record: self.token_owner_record.key(),
+            amount,
+            governing_token_deposit_amount: self.token_owner_record.governing_token_deposit_amount,
+            withdrawn_at: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
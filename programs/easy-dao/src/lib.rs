@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 pub mod state;
 pub mod instructions;
 pub mod error;
+pub mod events;
 
 pub use instructions::*;
 pub use state::*;
@@ -25,9 +26,28 @@ pub mod easy_dao {
         id: u64,
         name: String,
         realm_config: RealmConfig,
-        governing_token_config: GoverningTokenConfig
+        governing_token_config: GoverningTokenConfig,
+        council_token_config: GoverningTokenConfig
     ) -> Result<()> {
-        ctx.accounts.process(id, name, realm_config, governing_token_config)
+        ctx.accounts.process(id, name, realm_config, governing_token_config, council_token_config)
+    }
+
+    /// 设置、移交或移除 Realm 治理权拥有者
+    pub fn set_realm_authority(
+        ctx: Context<SetRealmAuthority>,
+        action: SetRealmAuthorityAction
+    ) -> Result<()> {
+        ctx.accounts.process(action)
+    }
+
+    /// 更新 Realm 配置与治理代币配置
+    pub fn set_realm_config(
+        ctx: Context<SetRealmConfig>,
+        realm_config: RealmConfig,
+        community_token_config: GoverningTokenConfig,
+        council_token_config: GoverningTokenConfig
+    ) -> Result<()> {
+        ctx.accounts.process(realm_config, community_token_config, council_token_config)
     }
 
     /// 创建治理代币拥有者账户
@@ -45,6 +65,46 @@ pub mod easy_dao {
         ctx.accounts.process(amount)
     }
 
+    /// 取出治理代币
+    pub fn withdraw_governing_tokens(
+        ctx: Context<WithdrawGoverningTokens>,
+        amount: u64
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.bumps.realm, amount)
+    }
+
+    /// 设置或清除治理代理人
+    pub fn set_governance_delegate(
+        ctx: Context<SetGovernanceDelegate>,
+        new_governance_delegate: Option<Pubkey>
+    ) -> Result<()> {
+        ctx.accounts.process(new_governance_delegate)
+    }
+
+    /// 锁定用户治理账户
+    pub fn set_token_owner_record_lock(
+        ctx: Context<SetTokenOwnerRecordLock>,
+        lock_id: u8,
+        expiry: u64
+    ) -> Result<()> {
+        ctx.accounts.process(lock_id, expiry)
+    }
+
+    /// 解除用户治理账户的锁
+    pub fn relinquish_token_owner_record_lock(
+        ctx: Context<RelinquishTokenOwnerRecordLock>,
+        lock_id: u8
+    ) -> Result<()> {
+        ctx.accounts.process(lock_id)
+    }
+
+    /// 关闭代币持有记录并退还租金
+    pub fn close_token_owner_record(
+        ctx: Context<CloseTokenOwnerRecord>
+    ) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// 创建治理账户
     pub fn create_governance(
         ctx: Context<CreateGovernance>,
@@ -53,13 +113,44 @@ pub mod easy_dao {
         ctx.accounts.process(ctx.bumps.governance, governance_config)
     }
 
+    /// 更新治理配置（需 Governance PDA 签名）
+    pub fn set_governance_config(
+        ctx: Context<SetGovernanceConfig>,
+        config: GovernanceConfig
+    ) -> Result<()> {
+        ctx.accounts.process(config)
+    }
+
+    /// 创建治理原生金库
+    pub fn create_native_treasury(
+        ctx: Context<CreateNativeTreasury>
+    ) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// 创建提案
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
         name: String, 
         description_link: String,
+        vote_type: VoteType,
+        options: Vec<String>,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.bumps.proposal_deposit, name, description_link, vote_type, options)
+    }
+
+    /// 取消提案
+    pub fn cancel_proposal(
+        ctx: Context<CancelProposal>
+    ) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// 将超过最长存续时间的草稿提案标记为过期
+    pub fn expire_draft_proposal(
+        ctx: Context<ExpireDraftProposal>
     ) -> Result<()> {
-        ctx.accounts.process(name, description_link)
+        ctx.accounts.process()
     }
 
     /// 添加必须签署人
@@ -83,12 +174,29 @@ pub mod easy_dao {
         ctx.accounts.process()
     }
 
+    /// 关闭签名记录并退还租金
+    pub fn close_signatory_record(
+        ctx: Context<CloseSignatoryRecord>
+    ) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// 添加提案指令
     pub fn add_transaction(
         ctx: Context<AddTransaction>,
-        instruction_data: InstructionData
+        option_index: u8,
+        transaction_index: u16,
+        hold_up_time: u32,
+        instructions: Vec<InstructionData>
     ) -> Result<()> {
-        ctx.accounts.process(instruction_data)
+        ctx.accounts.process(option_index, transaction_index, hold_up_time, instructions)
+    }
+
+    /// 移除草稿提案中的最后一条指令
+    pub fn remove_transaction(
+        ctx: Context<RemoveTransaction>
+    ) -> Result<()> {
+        ctx.accounts.process()
     }
 
     /// 签署提案
@@ -120,6 +228,13 @@ pub mod easy_dao {
         ctx.accounts.process()
     }
 
+    /// 关闭已释放的投票记录并退还租金
+    pub fn close_vote_record(
+        ctx: Context<CloseVoteRecord>
+    ) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// 执行提案指令
     pub fn execute_transaction(
         ctx: Context<ExecuteTransaction>
@@ -127,6 +242,13 @@ pub mod easy_dao {
         process_execute_transaction(ctx)
     }
 
+    /// 由提案发起人将可执行后又经过一个锁定期仍无法执行的提案交易标记为失败
+    pub fn flag_transaction_error(
+        ctx: Context<FlagTransactionError>
+    ) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// 退还提案押金
     pub fn refund_proposal_deposit(
         ctx: Context<RefundProposalDeposit>
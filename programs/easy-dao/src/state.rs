@@ -10,6 +10,7 @@ pub mod required_signatory;
 pub mod signatory_record;
 pub mod vote_record;
 pub mod proposal_transaction;
+pub mod voter_weight_record;
 
 pub use realm_config::*;
 pub use realm::*;
@@ -21,6 +22,7 @@ pub use required_signatory::*;
 pub use signatory_record::*;
 pub use vote_record::*;
 pub use proposal_transaction::*;
+pub use voter_weight_record::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub enum GovernanceAccountType {
@@ -1,33 +1,58 @@
 //! 创建管理目标账户
 use anchor_lang::prelude::*;
 
-use super::{GovernanceAccountType, VoteThreshold};
+use super::{GovernanceAccountType, Realm, VoteThreshold};
 use crate::error::GovernanceError;
 
 
-// #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
-// /// 投票提前通过机制
-// pub enum VoteTipping {
-//     /// 严格投票，不支持提前结束
-//     Strict,
-//     /// 允许提前通过（如达到门槛时即结束）
-//     Early,
-//     /// 禁用（投票始终无效/不计票）
-//     Disabled,
-// }
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+/// 投票提前结束机制
+pub enum VoteTipping {
+    /// 严格模式，仅在剩余票权无法改变结果时提前结束
+    Strict,
+    /// 允许提前通过（赞成票达到门槛且多于反对票即结束）
+    Early,
+    /// 禁用，只能在投票期结束后 finalize
+    Disabled,
+}
+
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+/// 提案押金的支付方式
+pub enum ProposalDepositType {
+    /// 以 SOL 支付，存放在 ProposalDeposit 账户中
+    Sol,
+    /// 以提案所用的治理代币支付，存放在 ProposalDeposit 的关联代币账户中
+    GoverningTokens,
+}
 
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
-// DAO 治理参数配置（社区投票专用版）
+// DAO 治理参数配置（社区与理事会投票）
 pub struct GovernanceConfig {
     /// 社区投票门槛
     /// 例: YesVotePercentage(60) 表示需有 60% 赞成票才算通过
     pub community_vote_threshold: VoteThreshold,
 
+    /// 社区投票提前结束机制
+    pub community_vote_tipping: VoteTipping,
+
     /// 创建提案所需的最小社区权重
     /// 例: 至少质押 1000 token 才能发起提案
     pub min_community_weight_to_create_proposal: u64,
 
+    /// 理事会投票门槛
+    pub council_vote_threshold: VoteThreshold,
+
+    /// 理事会投票提前结束机制
+    pub council_vote_tipping: VoteTipping,
+
+    /// 创建提案所需的最小理事会权重
+    pub min_council_weight_to_create_proposal: u64,
+
+    /// 理事会对社区提案的否决门槛，Disabled 表示不允许否决
+    pub council_veto_vote_threshold: VoteThreshold,
+
     /// 提案中每笔事务的锁定时长（单位：秒）
     pub transactions_hold_up_time: u32,
 
@@ -36,7 +61,31 @@ pub struct GovernanceConfig {
     pub voting_base_time: u32,
 
     /// 投票结束后的冷却期（单位：秒）
-    pub voting_cool_off_time: u32
+    pub voting_cool_off_time: u32,
+
+    /// 免押金的活跃提案数量，超出部分按数量递增收取押金
+    /// 例: 10 表示前 10 个活跃提案无需押金，第 11 个缴纳 1 份，第 12 个缴纳 2 份
+    /// None 时使用 DEFAULT_DEPOSIT_EXEMPT_PROPOSAL_COUNT
+    pub deposit_exempt_proposal_count: Option<u8>,
+
+    /// 每份提案押金的数额（SOL 时单位为 lamports，代币时为最小单位），0 表示不收押金
+    /// None 时收取 SECURITY_DEPOSIT_BASE_LAMPORTS，仅适用于 SOL 押金
+    pub proposal_deposit_amount: Option<u64>,
+
+    /// 提案押金的支付方式
+    pub proposal_deposit_type: ProposalDepositType,
+
+    /// 押金罚没的投票率门槛（百分比），None 表示不罚没
+    /// 开启后被否决（Vetoed）、投票率低于该值而失败（Defeated）或进入投票后被取消的提案，押金转入治理金库
+    /// 提案进入投票时快照该值，之后修改配置不影响已开始投票的提案
+    pub deposit_slash_turnout_percentage: Option<u8>,
+
+    /// 提案草稿的最长存续时间（单位：秒）
+    /// 超时仍未进入投票的提案可被任何人标记为 Expired
+    pub max_draft_time: u32,
+
+    /// 交易被标记为执行失败后仍允许重试执行的时长（单位：秒），0 表示不允许重试
+    pub transaction_retry_time: u32,
 }
 
 
@@ -46,8 +95,10 @@ pub struct Governance {
     pub account_type: GovernanceAccountType,
     /// 所属的 Realm（DAO 管理域）的 Pubkey
     pub realm: Pubkey,
-    /// 治理对象的种子（可以用来标记或辅助 PDA 派生）
-    pub governance_seed: u8,
+    /// 被治理的账户（如程序、mint 或金库），同时作为 PDA 派生种子
+    pub governed_account: Pubkey,
+    /// PDA bump
+    pub bump: u8,
     /// 社区治理参数配置
     pub config: GovernanceConfig,
     /// 当前需要签名的成员数量（如多签/执行时需要）
@@ -60,12 +111,52 @@ pub struct Governance {
 impl Governance {
     /// 免押金提案的默认数量
     pub const DEFAULT_DEPOSIT_EXEMPT_PROPOSAL_COUNT: u8 = 10;
-    /// 创建提案时需要缴纳的安全押金（投票结束或提案取消后可退还)
+    /// 创建提案时需要缴纳的默认安全押金（投票结束或提案取消后可退还)
     pub const SECURITY_DEPOSIT_BASE_LAMPORTS: u64 = 100_000_000; // 0.1 SOL
-    /// 账户大小
-    pub const LEN: usize = 8 + 1 + 32 + 1 + 22 + 1 + 8;
+    /// 账户大小，config 末尾为免押金数量(2) + 押金数额(9) + 押金类型(1) + 罚没投票率(2)
+    /// + 草稿时长(4) + 重试时长(4)，其后为签署人数量(1) + 活跃提案数量(8)
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 1 + 36 + 2 + 9 + 1 + 2 + 4 + 4 + 1 + 8;
     /// 种子
     pub const GOVERNANCE_SEED: &'static [u8] = b"governance";
+    /// 原生金库种子，金库 PDA = [NATIVE_TREASURY_SEED, governance]
+    pub const NATIVE_TREASURY_SEED: &'static [u8] = b"native-treasury";
+
+    /// Governance PDA 的签名种子
+    pub fn get_signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            self.realm.as_ref(),
+            Self::GOVERNANCE_SEED,
+            self.governed_account.as_ref(),
+            std::slice::from_ref(&self.bump)
+        ]
+    }
+
+    /// 创建与更新治理配置时共用的校验
+    pub fn assert_is_valid_governance_config(config: &GovernanceConfig) -> Result<()> {
+        Self::assert_is_valid_vote_threshold(&config.community_vote_threshold)?;
+        Self::assert_is_valid_vote_threshold(&config.council_vote_threshold)?;
+        Self::assert_is_valid_vote_threshold(&config.council_veto_vote_threshold)?;
+
+        require!(config.voting_base_time > 0, GovernanceError::InvalidVotingDuration);
+        require!(config.max_draft_time > 0, GovernanceError::InvalidMaxDraftTime);
+
+        // 默认押金以 lamports 计，代币押金必须显式配置数额
+        if config.proposal_deposit_type == ProposalDepositType::GoverningTokens {
+            require!(
+                config.proposal_deposit_amount.is_some(),
+                GovernanceError::MissingProposalDepositAmount
+            );
+        }
+
+        if let Some(turnout_percentage) = config.deposit_slash_turnout_percentage {
+            require!(
+                turnout_percentage <= 100,
+                GovernanceError::InvalidDepositSlashTurnoutPercentage
+            );
+        }
+
+        Ok(())
+    }
 
     pub fn assert_is_valid_vote_threshold(
         vote_threshold: &VoteThreshold
@@ -82,17 +173,59 @@ impl Governance {
         Ok(())
     }
 
-    pub fn resolve_vote_threshold(&self) -> Result<()> {
-        if self.config.community_vote_threshold == VoteThreshold::Disabled {
+    /// 按治理代币 mint 获取投票门槛
+    pub fn resolve_vote_threshold(&self, realm: &Realm, mint: &Pubkey) -> Result<VoteThreshold> {
+        let vote_threshold = if realm.is_council_mint(mint) {
+            &self.config.council_vote_threshold
+        } else {
+            &self.config.community_vote_threshold
+        };
+
+        if *vote_threshold == VoteThreshold::Disabled {
             return err!(GovernanceError::GoverningTokenMintNotAllowedToVote)
         }
 
-        Ok(())
+        Ok(vote_threshold.clone())
+    }
+
+    /// 按治理代币 mint 获取投票提前结束机制
+    pub fn get_vote_tipping(&self, realm: &Realm, mint: &Pubkey) -> VoteTipping {
+        if realm.is_council_mint(mint) {
+            self.config.council_vote_tipping.clone()
+        } else {
+            self.config.community_vote_tipping.clone()
+        }
     }
 
+    /// 理事会否决门槛，仅适用于社区提案
+    pub fn resolve_veto_vote_threshold(&self) -> Result<VoteThreshold> {
+        if self.config.council_veto_vote_threshold == VoteThreshold::Disabled {
+            return err!(GovernanceError::GoverningTokenMintNotAllowedToVote)
+        }
+
+        Ok(self.config.council_veto_vote_threshold.clone())
+    }
+
+    /// 按治理代币 mint 获取创建提案所需的最小权重
+    pub fn get_min_weight_to_create_proposal(&self, realm: &Realm, mint: &Pubkey) -> u64 {
+        if realm.is_council_mint(mint) {
+            self.config.min_council_weight_to_create_proposal
+        } else {
+            self.config.min_community_weight_to_create_proposal
+        }
+    }
+
+    /// 计算新提案需要缴纳的押金，需在 active_proposal_count 递增之前调用
     pub fn get_proposal_deposit_amount(&self) -> u64 {
+        let deposit_exempt_proposal_count = self.config.deposit_exempt_proposal_count
+            .unwrap_or(Self::DEFAULT_DEPOSIT_EXEMPT_PROPOSAL_COUNT);
+        let proposal_deposit_amount = self.config.proposal_deposit_amount
+            .unwrap_or(Self::SECURITY_DEPOSIT_BASE_LAMPORTS);
+
         self.active_proposal_count
-            .saturating_mul(Self::SECURITY_DEPOSIT_BASE_LAMPORTS)
+            .saturating_add(1)
+            .saturating_sub(deposit_exempt_proposal_count as u64)
+            .saturating_mul(proposal_deposit_amount)
     }
 
 }
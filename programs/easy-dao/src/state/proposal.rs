@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 
 use crate::error::GovernanceError;
 
-use super::{GovernanceAccountType, GovernanceConfig, MintMaxVoterWeightSource, VoteThreshold};
+use super::{GovernanceAccountType, GovernanceConfig, MintMaxVoterWeightSource, ProposalTransaction, Vote, VoteChoice, VoteThreshold, VoteTipping};
 
 /// 提案生命周期状态（精简版）
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq, Eq)]
@@ -21,7 +21,63 @@ pub enum ProposalState {
     /// 执行失败
     ExecutionFailed,
     /// 已完成
-    Completed
+    Completed,
+    /// 已被发起人取消
+    Cancelled,
+    /// 被理事会否决
+    Vetoed,
+    /// 草稿超过最长存续时间仍未进入投票，视为被放弃
+    Expired
+}
+
+
+/// 单个选项的投票结果
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq, Eq)]
+pub enum OptionVoteResult {
+    /// 投票尚未结束
+    None,
+    /// 选项通过
+    Succeeded,
+    /// 选项未通过
+    Defeated
+}
+
+
+/// 提案选项
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct ProposalOption {
+    /// 选项标签（最长 50 字节）
+    pub label: String,
+    /// 选项获得的赞成票权重
+    pub vote_weight: u64,
+    /// 选项投票结果
+    pub vote_result: OptionVoteResult,
+    /// 选项包含的交易数量
+    pub transactions_count: u16,
+    /// 选项已执行的交易数量，交易按索引顺序执行
+    pub transactions_executed_count: u16,
+    /// 选项最近一次执行交易的时间，下一条交易从此时起轮到执行；尚未执行过交易时为 None
+    pub last_transaction_executed_at: Option<u64>,
+}
+
+impl ProposalOption {
+    pub const MAX_LABEL_LEN: usize = 50;
+    /// 4+50: label，8: vote_weight，1: vote_result，2+2: 交易计数，9: last_transaction_executed_at
+    pub const LEN: usize = 4 + Self::MAX_LABEL_LEN + 8 + 1 + 2 + 2 + 9;
+}
+
+
+/// 提案投票类型
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq, Eq)]
+pub enum VoteType {
+    /// 单选：只有一个选项，可投赞成或反对
+    SingleChoice,
+    /// 多选：投票人最多选择 max_voter_options 个选项，
+    /// 得票最高且达到门槛的前 max_winning_options 个选项通过
+    MultiChoice {
+        max_voter_options: u8,
+        max_winning_options: u8,
+    }
 }
 
 
@@ -34,18 +90,30 @@ pub struct Proposal {
     pub governance: Pubkey,
     /// 提案发起人的 TokenOwnerRecord
     pub token_owner_record: Pubkey,
+    /// 投票使用的治理代币 mint（社区或理事会）
+    pub governing_token_mint: Pubkey,
     /// 需签署审核人数
     pub signatories_count: u8,
     /// 已签署通过人数
     pub signatories_signed_off_count: u8,
+    /// 提案创建（进入草稿）的 Unix 时间戳（秒）
+    pub draft_at: u64,
     /// 签署通过时间
     pub signing_off_at: Option<u64>,
     /// 当前提案状态
     pub state: ProposalState,
-    /// YES 赞成票权重总和
-    pub yes_vote_weight: u64,
-    /// NO 反对票权重总和
+    /// 投票类型
+    pub vote_type: VoteType,
+    /// 提案选项，单选提案只有一个选项
+    pub options: Vec<ProposalOption>,
+    /// NO 反对票权重总和，仅单选提案可投
     pub no_vote_weight: u64,
+    /// 弃权票权重总和，只计入参与度
+    pub abstain_vote_weight: u64,
+    /// 理事会否决票权重总和
+    pub veto_vote_weight: u64,
+    /// 参与投票的票权总和（每张票只计一次，不含否决票），用于计算投票率
+    pub cast_vote_weight: u64,
     /// 投票开始的 Unix 时间戳（秒）
     pub voting_started_at: u64,
     /// 投票完成的 Unix 时间戳（秒）；实际完成时间；若投票尚未结束则为 None
@@ -54,8 +122,10 @@ pub struct Proposal {
     pub closed_at: Option<u64>,
     /// 提案通过门槛配置
     pub vote_threshold: Option<VoteThreshold>,
-    /// 是否包含需要执行的链上指令
-    pub has_transaction: bool,
+    /// 投票结束时的最大票权，投票未结束时为 None
+    pub max_vote_weight: Option<u64>,
+    /// 进入投票时从治理配置快照的押金罚没投票率门槛，之后修改配置不影响本提案
+    pub deposit_slash_turnout_percentage: Option<u8>,
     /// 提案标题
     pub name: String,
     /// 提案详情的外链（IPFS / Arweave 等）
@@ -64,29 +134,331 @@ pub struct Proposal {
 
 
 impl Proposal {
-    /// 账户大小，标题最多50字，详情外链255
-    pub const LEN: usize = 8 + 1 + 32 * 2 + 2 + 9 + 1 + 8 * 3 + 9 + 9 + 3 + 1 + 4 + 64 + 4 + 255;
+    /// 提案最多可包含的选项数量
+    pub const MAX_OPTIONS: usize = 10;
+    /// 交易可执行后、发起人可以标记失败前的最短执行窗口（秒）
+    pub const MIN_TRANSACTION_EXECUTION_WINDOW: u64 = 60;
+    /// 账户大小，标题最多50字，详情外链255，最多 MAX_OPTIONS 个选项
+    pub const LEN: usize = 8 + 1 + 32 * 3 + 2 + 8 + 9 + 1 + 3 + 4 + Self::MAX_OPTIONS * ProposalOption::LEN
+        + 8 * 5 + 9 + 9 + 3 + 9 + 2 + 4 + 64 + 4 + 255;
+
+    /// 校验投票类型与选项
+    pub fn assert_valid_options(vote_type: &VoteType, options: &[String]) -> Result<()> {
+        require!(
+            !options.is_empty() && options.len() <= Self::MAX_OPTIONS,
+            GovernanceError::InvalidProposalOptions
+        );
+        require!(
+            options.iter().all(|label| label.len() <= ProposalOption::MAX_LABEL_LEN),
+            GovernanceError::InvalidProposalOptions
+        );
+
+        match *vote_type {
+            VoteType::SingleChoice => {
+                require!(options.len() == 1, GovernanceError::InvalidProposalOptions);
+            },
+            VoteType::MultiChoice { max_voter_options, max_winning_options } => {
+                let options_count = options.len() as u8;
+                require!(
+                    (1..=options_count).contains(&max_voter_options)
+                        && (1..=options_count).contains(&max_winning_options),
+                    GovernanceError::InvalidVoteType
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 校验投票选择并返回每个选项应计入的权重
+    pub fn get_option_vote_weights(&self, choices: &[VoteChoice], vote_weight: u64) -> Result<Vec<u64>> {
+        require!(choices.len() == self.options.len(), GovernanceError::InvalidVote);
+
+        // 排名投票暂未支持，rank 必须为 0
+        require!(choices.iter().all(|choice| choice.rank == 0), GovernanceError::InvalidVote);
+
+        let selected_count = choices.iter().filter(|choice| choice.weight_percentage > 0).count();
+        let max_selected = match self.vote_type {
+            VoteType::SingleChoice => 1,
+            VoteType::MultiChoice { max_voter_options, .. } => max_voter_options as usize,
+        };
+        require!(
+            selected_count > 0 && selected_count <= max_selected,
+            GovernanceError::InvalidVote
+        );
+
+        // 认可投票：每个被选中的选项获得全部票权
+        choices
+            .iter()
+            .map(|choice| match choice.weight_percentage {
+                0 => Ok(0),
+                100 => Ok(vote_weight),
+                _ => err!(GovernanceError::InvalidVote),
+            })
+            .collect()
+    }
+
+    /// 是否有通过的选项包含待执行交易
+    pub fn has_executable_transactions(&self) -> bool {
+        self.options
+            .iter()
+            .any(|option| option.vote_result == OptionVoteResult::Succeeded && option.transactions_count > 0)
+    }
+
+    /// 所有通过选项的交易是否都已执行
+    pub fn all_transactions_executed(&self) -> bool {
+        self.options
+            .iter()
+            .filter(|option| option.vote_result == OptionVoteResult::Succeeded)
+            .all(|option| option.transactions_executed_count == option.transactions_count)
+    }
+
+    /// 校验交易可以被执行或标记失败：提案处于执行阶段、所属选项已通过、
+    /// 是该选项下一条待执行的交易且已过锁定期
+    pub fn assert_can_execute_transaction(
+        &self,
+        proposal_transaction: &ProposalTransaction,
+        now: u64
+    ) -> Result<()> {
+        require!(
+            matches!(self.state, ProposalState::Executing | ProposalState::ExecutionFailed),
+            GovernanceError::InvalidStateCannotExecuteTransaction
+        );
+
+        if proposal_transaction.executed_at.is_some() {
+            return err!(GovernanceError::TransactionAlreadyExecuted);
+        }
+
+        let option = self.options
+            .get(proposal_transaction.option_index as usize)
+            .ok_or(GovernanceError::InvalidOptionIndex)?;
+
+        require!(
+            option.vote_result == OptionVoteResult::Succeeded,
+            GovernanceError::CannotExecuteDefeatedOption
+        );
 
-    pub fn assert_can_cast_vote(&self, config: &GovernanceConfig) -> Result<()> {
+        require!(
+            proposal_transaction.transaction_index == option.transactions_executed_count,
+            GovernanceError::InvalidTransactionExecutionOrder
+        );
+
+        let voting_completed_at = self.voting_completed_at
+            .ok_or(GovernanceError::InvalidStateCannotExecuteTransaction)?;
+
+        require!(
+            now >= voting_completed_at + proposal_transaction.hold_up_time as u64,
+            GovernanceError::CannotExecuteTransactionWithinHoldUpTime
+        );
+
+        Ok(())
+    }
+
+    /// 交易可执行后还需经过执行窗口才能标记失败，窗口内任何人都可以执行，
+    /// 避免发起人在交易刚可执行时单方面终止已通过的提案。
+    /// 交易在锁定期结束且同选项的前一条交易执行后才可执行，窗口为 hold_up_time，
+    /// 且不少于 MIN_TRANSACTION_EXECUTION_WINDOW；同一时间只能有一条交易处于失败状态
+    pub fn assert_can_flag_transaction_error(
+        &self,
+        proposal_transaction: &ProposalTransaction,
+        now: u64
+    ) -> Result<()> {
+        require!(
+            self.state == ProposalState::Executing,
+            GovernanceError::InvalidProposalState
+        );
+
+        self.assert_can_execute_transaction(proposal_transaction, now)?;
+
+        let voting_completed_at = self.voting_completed_at
+            .ok_or(GovernanceError::InvalidStateCannotExecuteTransaction)?;
+        let hold_up_time = proposal_transaction.hold_up_time as u64;
+        let option = &self.options[proposal_transaction.option_index as usize];
+
+        let executable_at = option.last_transaction_executed_at
+            .unwrap_or(voting_completed_at)
+            .max(voting_completed_at + hold_up_time);
+        let execution_window = hold_up_time.max(Self::MIN_TRANSACTION_EXECUTION_WINDOW);
+
+        require!(
+            now >= executable_at + execution_window,
+            GovernanceError::CannotFlagTransactionWithinExecutionWindow
+        );
+
+        Ok(())
+    }
+
+    /// 冷却期时长；冷却期内只能投反对或否决票，多选提案没有反对票，因此不设冷却期
+    fn get_voting_cool_off_time(&self, config: &GovernanceConfig) -> u64 {
+        match self.vote_type {
+            VoteType::SingleChoice => config.voting_cool_off_time as u64,
+            VoteType::MultiChoice { .. } => 0,
+        }
+    }
+
+    /// 投票期结束时间：投票基础时长加冷却期
+    pub fn get_voting_end_time(&self, config: &GovernanceConfig) -> u64 {
+        self.voting_started_at
+            + config.voting_base_time as u64
+            + self.get_voting_cool_off_time(config)
+    }
+
+    /// 是否处于冷却期：投票基础时长结束后、整个投票期结束前
+    pub fn is_in_cool_off_time(&self, config: &GovernanceConfig, now: u64) -> bool {
+        let base_end = self.voting_started_at + config.voting_base_time as u64;
+
+        now >= base_end && now < self.get_voting_end_time(config)
+    }
+
+    /// 冷却期内只能投反对或否决票
+    pub fn assert_can_cast_vote(&self, config: &GovernanceConfig, vote: &Vote) -> Result<()> {
         require!(
             self.state == ProposalState::Voting, 
             GovernanceError::InvalidProposalState
         );
 
         let now = Clock::get()?.unix_timestamp.try_into()?;
-        let end = self.voting_started_at 
-            + config.voting_base_time as u64 
-            + config.voting_cool_off_time as u64;
+        let end = self.get_voting_end_time(config);
+
+        require!(end > now, GovernanceError::ProposalVotingTimeExpired);
+
+        if self.is_in_cool_off_time(config, now) {
+            require!(
+                matches!(vote, Vote::No | Vote::Veto),
+                GovernanceError::VoteNotAllowedInCoolOffTime
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 投票期间撤回投票：基础投票期内可撤回任意投票，
+    /// 冷却期内只能撤回赞成票，防止撤回反对票影响结果
+    pub fn assert_can_relinquish_vote_in_voting(&self, config: &GovernanceConfig, vote: &Vote) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp.try_into()?;
+        let end = self.get_voting_end_time(config);
 
+        // 投票期已过但尚未 finalize 时不允许撤回，避免改变最终结果
         require!(end > now, GovernanceError::ProposalVotingTimeExpired);
 
+        if self.is_in_cool_off_time(config, now) {
+            require!(
+                matches!(vote, Vote::Yes | Vote::Approve(_)),
+                GovernanceError::CannotRelinquishInVotingPeriod
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 将投票权重计入对应的计票
+    pub fn add_vote(&mut self, vote: &Vote, vote_weight: u64) -> Result<()> {
+        if *vote != Vote::Veto {
+            self.cast_vote_weight = self.cast_vote_weight
+                .checked_add(vote_weight)
+                .ok_or(error!(GovernanceError::Overflow))?;
+        }
+
+        match vote {
+            Vote::Yes | Vote::No => {
+                // 赞成/反对只适用于单选提案
+                require!(
+                    self.vote_type == VoteType::SingleChoice,
+                    GovernanceError::InvalidVote
+                );
+                let tally = if *vote == Vote::Yes {
+                    &mut self.options[0].vote_weight
+                } else {
+                    &mut self.no_vote_weight
+                };
+                *tally = tally
+                    .checked_add(vote_weight)
+                    .ok_or(error!(GovernanceError::Overflow))?;
+            },
+            Vote::Approve(choices) => {
+                let option_vote_weights = self.get_option_vote_weights(choices, vote_weight)?;
+                for (option, option_vote_weight) in self.options.iter_mut().zip(option_vote_weights) {
+                    option.vote_weight = option.vote_weight
+                        .checked_add(option_vote_weight)
+                        .ok_or(error!(GovernanceError::Overflow))?;
+                }
+            },
+            Vote::Abstain => {
+                self.abstain_vote_weight = self.abstain_vote_weight
+                    .checked_add(vote_weight)
+                    .ok_or(error!(GovernanceError::Overflow))?;
+            },
+            Vote::Veto => {
+                self.veto_vote_weight = self.veto_vote_weight
+                    .checked_add(vote_weight)
+                    .ok_or(error!(GovernanceError::Overflow))?;
+            }
+        }
+
         Ok(())
     }
 
+    /// 从计票中移除已记录的投票权重
+    pub fn remove_vote(&mut self, vote: &Vote, vote_weight: u64) -> Result<()> {
+        if *vote != Vote::Veto {
+            self.cast_vote_weight = self.cast_vote_weight
+                .checked_sub(vote_weight)
+                .ok_or(error!(GovernanceError::Overflow))?;
+        }
+
+        match vote {
+            Vote::Yes => {
+                self.options[0].vote_weight = self.options[0].vote_weight
+                    .checked_sub(vote_weight)
+                    .ok_or(error!(GovernanceError::Overflow))?;
+            },
+            Vote::No => {
+                self.no_vote_weight = self.no_vote_weight
+                    .checked_sub(vote_weight)
+                    .ok_or(error!(GovernanceError::Overflow))?;
+            },
+            Vote::Approve(choices) => {
+                let option_vote_weights = self.get_option_vote_weights(choices, vote_weight)?;
+                for (option, option_vote_weight) in self.options.iter_mut().zip(option_vote_weights) {
+                    option.vote_weight = option.vote_weight
+                        .checked_sub(option_vote_weight)
+                        .ok_or(error!(GovernanceError::Overflow))?;
+                }
+            },
+            Vote::Abstain => {
+                self.abstain_vote_weight = self.abstain_vote_weight
+                    .checked_sub(vote_weight)
+                    .ok_or(error!(GovernanceError::Overflow))?;
+            },
+            Vote::Veto => {
+                self.veto_vote_weight = self.veto_vote_weight
+                    .checked_sub(vote_weight)
+                    .ok_or(error!(GovernanceError::Overflow))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn assert_can_cancel(&self, config: &GovernanceConfig) -> Result<()> {
+        match self.state {
+            ProposalState::Draft | ProposalState::SigningOff => Ok(()),
+            ProposalState::Voting => {
+                let now = Clock::get()?.unix_timestamp.try_into()?;
+                let end = self.get_voting_end_time(config);
+
+                require!(end > now, GovernanceError::ProposalVotingTimeExpired);
+
+                Ok(())
+            },
+            _ => err!(GovernanceError::InvalidProposalState)
+        }
+    }
+
+    /// 由 mint 供应量计算最大票权，与投票记录的票权一致，均以代币最小单位计
     pub fn get_max_voter_weight_from_mint_supply(
         &self, 
         mint_supply: u64,
-        mint_decimals: u8,
         mint_max_voter_weight_source: MintMaxVoterWeightSource
     ) -> Option<u128> {
         let max_voter_weight = match mint_max_voter_weight_source {
@@ -94,19 +466,18 @@ impl Proposal {
                 u128::from(mint_supply)
                     .checked_mul(u128::from(fraction))?
                     .checked_div(u128::from(MintMaxVoterWeightSource::SUPPLY_FRACTION_BASE))?
-                    .checked_div(10u128.pow(mint_decimals as u32))?
             },
             MintMaxVoterWeightSource::Absolute(amount) => u128::from(amount),
         };
         Some(max_voter_weight)
     }
 
-    pub fn maybe_finalize_vote(
-        &mut self,
+    /// 按门槛百分比计算所需的票权
+    fn get_vote_threshold_weight(
         max_voter_weight: u128,
-        vote_threshold: VoteThreshold
-    ) -> Result<bool> {
-        let numerator = match vote_threshold {
+        vote_threshold: &VoteThreshold
+    ) -> Result<u64> {
+        let numerator = match *vote_threshold {
             VoteThreshold::YesVotePercentage(yes_vote_threshold_percentage) => {
                 u128::from(yes_vote_threshold_percentage)
             },
@@ -115,47 +486,191 @@ impl Proposal {
             }
         };
 
-        let yes_vote_threshold = (numerator * max_voter_weight + 100 - 1) / 100;
+        let threshold_weight = (numerator * max_voter_weight).div_ceil(100);
 
-        if let Ok(threshold_u64) = u64::try_from(yes_vote_threshold) {
-            if self.yes_vote_weight >= threshold_u64 {
-                let now = Clock::get()?.unix_timestamp.try_into()?;
+        u64::try_from(threshold_weight).map_err(|_| error!(GovernanceError::Overflow))
+    }
 
-                if self.has_transaction {
-                    self.state = ProposalState::Executing;
-                } else {
-                    self.state = ProposalState::Completed;
-                    self.closed_at = Some(now);
-                }
-                self.voting_completed_at = Some(now);
-                self.vote_threshold = Some(vote_threshold);
-                Ok(true)
-            } else {
-                Ok(false)
+    /// 投票期间提前结束，仅单选提案支持；弃权票不计入门槛，赞成票需达到门槛且多于反对票
+    /// Early：赞成票满足条件即通过
+    /// Strict：剩余未投票权全部投反对也无法改变结果时才通过；赞成票已无法满足条件时提前否决
+    pub fn maybe_finalize_vote(
+        &mut self,
+        max_voter_weight: u128,
+        vote_threshold: VoteThreshold,
+        vote_tipping: VoteTipping
+    ) -> Result<bool> {
+        if self.vote_type != VoteType::SingleChoice || vote_tipping == VoteTipping::Disabled {
+            return Ok(false);
+        }
+
+        let threshold_weight = Self::get_vote_threshold_weight(max_voter_weight, &vote_threshold)?;
+        let yes_vote_weight = self.options[0].vote_weight;
+        let no_vote_weight = self.no_vote_weight;
+
+        let passed = yes_vote_weight >= threshold_weight && yes_vote_weight > no_vote_weight;
+
+        if vote_tipping == VoteTipping::Early {
+            if passed {
+                self.options[0].vote_result = OptionVoteResult::Succeeded;
+                self.complete_voting(max_voter_weight, vote_threshold)?;
+                return Ok(true);
             }
+            return Ok(false);
+        }
+
+        let cast_vote_weight = u128::from(yes_vote_weight)
+            + u128::from(no_vote_weight)
+            + u128::from(self.abstain_vote_weight);
+        let remaining_vote_weight = u64::try_from(max_voter_weight.saturating_sub(cast_vote_weight))
+            .map_err(|_| error!(GovernanceError::Overflow))?;
+
+        if passed && yes_vote_weight > no_vote_weight.saturating_add(remaining_vote_weight) {
+            self.options[0].vote_result = OptionVoteResult::Succeeded;
+            self.complete_voting(max_voter_weight, vote_threshold)?;
+            Ok(true)
+        } else if yes_vote_weight.saturating_add(remaining_vote_weight) < threshold_weight
+            || no_vote_weight >= yes_vote_weight.saturating_add(remaining_vote_weight)
+        {
+            self.options[0].vote_result = OptionVoteResult::Defeated;
+            self.close_voting(ProposalState::Defeated, max_voter_weight, vote_threshold)?;
+            Ok(true)
         } else {
-            return err!(GovernanceError::Overflow);
+            Ok(false)
         }
     }
 
+    /// 投票期结束后计算各选项结果
+    /// 多选提案中达到门槛且得票最高的前 max_winning_options 个选项通过
+    pub fn finalize_vote(
+        &mut self,
+        max_voter_weight: u128,
+        vote_threshold: VoteThreshold
+    ) -> Result<()> {
+        let threshold_weight = Self::get_vote_threshold_weight(max_voter_weight, &vote_threshold)?;
+
+        let max_winning_options = match self.vote_type {
+            VoteType::SingleChoice => 1,
+            VoteType::MultiChoice { max_winning_options, .. } => max_winning_options as usize,
+        };
+
+        let mut ranked: Vec<usize> = (0..self.options.len())
+            .filter(|&i| {
+                let vote_weight = self.options[i].vote_weight;
+                vote_weight >= threshold_weight
+                    && (self.vote_type != VoteType::SingleChoice || vote_weight > self.no_vote_weight)
+            })
+            .collect();
+        ranked.sort_by(|&a, &b| self.options[b].vote_weight.cmp(&self.options[a].vote_weight));
+        ranked.truncate(max_winning_options);
+
+        for (i, option) in self.options.iter_mut().enumerate() {
+            option.vote_result = if ranked.contains(&i) {
+                OptionVoteResult::Succeeded
+            } else {
+                OptionVoteResult::Defeated
+            };
+        }
+
+        if ranked.is_empty() {
+            self.close_voting(ProposalState::Defeated, max_voter_weight, vote_threshold)
+        } else {
+            self.complete_voting(max_voter_weight, vote_threshold)
+        }
+    }
+
+    /// 投票通过：有待执行交易时进入 Executing，否则直接完成
+    fn complete_voting(&mut self, max_voter_weight: u128, vote_threshold: VoteThreshold) -> Result<()> {
+        if self.has_executable_transactions() {
+            let now = Clock::get()?.unix_timestamp.try_into()?;
+            self.state = ProposalState::Executing;
+            self.voting_completed_at = Some(now);
+            self.vote_threshold = Some(vote_threshold);
+            self.max_vote_weight = Some(u64::try_from(max_voter_weight).unwrap_or(u64::MAX));
+            Ok(())
+        } else {
+            self.close_voting(ProposalState::Completed, max_voter_weight, vote_threshold)
+        }
+    }
+
+    /// 投票结束且提案进入终态（Completed / Defeated / Vetoed），记录结束时间与最大票权
+    fn close_voting(
+        &mut self,
+        state: ProposalState,
+        max_voter_weight: u128,
+        vote_threshold: VoteThreshold
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp.try_into()?;
+
+        self.state = state;
+        self.voting_completed_at = Some(now);
+        self.closed_at = Some(now);
+        self.vote_threshold = Some(vote_threshold);
+        self.max_vote_weight = Some(u64::try_from(max_voter_weight).unwrap_or(u64::MAX));
+
+        Ok(())
+    }
+
+    /// 提案结束后押金是否应罚没进治理金库，按进入投票时快照的罚没门槛判断：
+    /// 被否决、投票率不足而失败，或进入投票后被发起人取消（避免在结果出来前取消逃避罚没）
+    pub fn is_deposit_slashed(&self) -> bool {
+        let Some(turnout_percentage) = self.deposit_slash_turnout_percentage else {
+            return false;
+        };
+
+        match self.state {
+            ProposalState::Vetoed | ProposalState::Cancelled => true,
+            ProposalState::Defeated => self.is_below_turnout(turnout_percentage),
+            _ => false,
+        }
+    }
+
+    /// 投票率是否低于给定百分比，投票尚未结束时返回 false
+    /// cast_vote_weight 与 max_vote_weight 均以代币最小单位计
+    pub fn is_below_turnout(&self, turnout_percentage: u8) -> bool {
+        match self.max_vote_weight {
+            Some(max_vote_weight) => {
+                u128::from(self.cast_vote_weight) * 100
+                    < u128::from(turnout_percentage) * u128::from(max_vote_weight)
+            },
+            None => false,
+        }
+    }
+
+    /// 否决票达到理事会否决门槛时，提案立即进入 Vetoed
+    pub fn maybe_veto(
+        &mut self,
+        max_voter_weight: u128,
+        veto_vote_threshold: VoteThreshold
+    ) -> Result<bool> {
+        let threshold_weight = Self::get_vote_threshold_weight(max_voter_weight, &veto_vote_threshold)?;
+
+        if self.veto_vote_weight >= threshold_weight {
+            self.close_voting(ProposalState::Vetoed, max_voter_weight, veto_vote_threshold)?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// 草稿或签署中的提案是否已超过最长存续时间
+    pub fn is_draft_expired(&self, config: &GovernanceConfig, now: u64) -> bool {
+        matches!(self.state, ProposalState::Draft | ProposalState::SigningOff)
+            && now > self.draft_at.saturating_add(config.max_draft_time as u64)
+    }
+
     pub fn assert_can_finalize_vote(
         &self,
         config: &GovernanceConfig
     ) -> Result<()> {
         let now = Clock::get()?.unix_timestamp.try_into()?;
-        let end = self.voting_started_at 
-            + config.voting_base_time as u64 
-            + config.voting_cool_off_time as u64;
+        let end = self.get_voting_end_time(config);
 
         require!(end < now, GovernanceError::ProposalStillInVoting);
 
+        // 未进入投票的提案通过 expire_draft_proposal 过期，而不是在此被否决
         require!(
-            matches!(
-                self.state,
-                ProposalState::Draft
-                    | ProposalState::SigningOff
-                    | ProposalState::Voting
-            ),
+            self.state == ProposalState::Voting,
             GovernanceError::ProposalNotFinalizable
         );
         
@@ -2,7 +2,7 @@
 //! 提案押金账户
 use anchor_lang::prelude::*;
 
-use super::GovernanceAccountType;
+use super::{GovernanceAccountType, ProposalDepositType};
 
 
 #[account]
@@ -12,11 +12,27 @@ pub struct ProposalDeposit {
     /// 提案地址
     pub proposal: Pubkey,
     /// 支付人
-    pub deposit_payer: Pubkey
+    pub deposit_payer: Pubkey,
+    /// 押金的支付方式
+    pub deposit_type: ProposalDepositType,
+    /// 押金数额（不含账户租金）
+    pub amount: u64,
+    /// PDA bump，代币押金退还时用于签名
+    pub bump: u8,
 }
 
 
 impl ProposalDeposit {
-    pub const LEN: usize = 8 + 1 + 32 + 32;
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 1 + 8 + 1;
     pub const PROPOSAL_DEPOSIT_SEED: &'static [u8] = b"proposal-deposit";
+
+    /// ProposalDeposit PDA 的签名种子
+    pub fn get_signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            Self::PROPOSAL_DEPOSIT_SEED,
+            self.deposit_payer.as_ref(),
+            self.proposal.as_ref(),
+            std::slice::from_ref(&self.bump)
+        ]
+    }
 }
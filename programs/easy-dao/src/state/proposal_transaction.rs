@@ -54,12 +54,22 @@ pub struct ProposalTransaction {
     pub account_type: GovernanceAccountType,
     /// 所属提案的 Pubkey
     pub proposal: Pubkey,
-    /// 要执行的指令内容
-    pub instruction: InstructionData,
+    /// 所属提案选项索引
+    pub option_index: u8,
+    /// 交易在选项内的索引，按顺序执行
+    pub transaction_index: u16,
+    /// 投票完成后需等待的时长（单位：秒），不得低于治理配置的最小值
+    pub hold_up_time: u32,
+    /// 要执行的指令列表，在同一笔交易中原子执行
+    pub instructions: Vec<InstructionData>,
     /// 指令执行的时间戳（Unix 秒），未执行则为 None
     pub executed_at: Option<u64>,
     /// 指令当前执行状态
     pub execution_status: ExecutionStatus,
+    /// 被提案发起人标记为执行失败的时间戳（Unix 秒），未标记则为 None
+    pub error_flagged_at: Option<u64>,
+    /// 支付账户租金的地址，关闭账户时退还给它
+    pub rent_payer: Pubkey,
 }
 
 
@@ -67,9 +77,21 @@ impl ProposalTransaction {
     /// - discriminator: 8字节
     /// - account_type: 1字节（GovernanceAccountType as u8）
     /// - proposal: 32字节（Pubkey）
+    /// - option_index: 1字节（u8）
+    /// - transaction_index: 2字节（u16）
+    /// - hold_up_time: 4字节（u32）
     /// - executed_at: 9字节（Option<u64>，1字节tag + 8字节内容）
     /// - execution_status: 1字节（ExecutionStatus as u8）
-    /// - instruction 字段序列化后的实际长度（可变长，需运行时单独计算）
-    pub const FIXED_LEN: usize = 8 + 1 + 32 + 9 + 1;
+    /// - error_flagged_at: 9字节（Option<u64>）
+    /// - rent_payer: 32字节（Pubkey）
+    /// - instructions 字段序列化后的实际长度（可变长，需运行时单独计算）
+    pub const FIXED_LEN: usize = 8 + 1 + 32 + 1 + 2 + 4 + 9 + 1 + 9 + 32;
     pub const SEED_PREFIX: &'static [u8] = b"proposal_transaction";
+
+    /// 账户所需空间：固定部分 + 指令列表（含 4 字节 Vec 长度前缀）
+    pub fn get_space(instructions: &[InstructionData]) -> usize {
+        Self::FIXED_LEN
+            + 4
+            + instructions.iter().map(|i| i.serialized_len()).sum::<usize>()
+    }
 }
@@ -1,6 +1,8 @@
 //! 治理账户
 use anchor_lang::prelude::*;
 
+use crate::error::GovernanceError;
+
 use super::GovernanceAccountType;
 
 
@@ -26,6 +28,32 @@ pub struct RealmConfig {
     pub community_mint_max_voter_weight_source: MintMaxVoterWeightSource,
 }
 
+impl RealmConfig {
+    /// 创建与更新 Realm 配置时共用的校验
+    pub fn assert_is_valid(&self) -> Result<()> {
+        match self.community_mint_max_voter_weight_source {
+            MintMaxVoterWeightSource::SupplyFraction(_fraction) => {},
+            MintMaxVoterWeightSource::Absolute(amount) => {
+                require!(amount > 0, GovernanceError::InvalidMintMaxVoterWeightSource);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+/// 设置 Realm 治理权拥有者的方式
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum SetRealmAuthorityAction {
+    /// 直接设置新的拥有者，不做校验
+    SetUnchecked,
+    /// 设置为该 Realm 下的某个 Governance 账户
+    SetChecked,
+    /// 移除拥有者
+    Remove
+}
+
 
 #[account]
 pub struct Realm {
@@ -38,21 +66,63 @@ pub struct Realm {
     pub community_mint: Pubkey,
     /// 社区治理代币账户 公钥地址
     pub community_token_account: Pubkey,
+    /// 理事会治理代币的 Mint 公钥地址（可选）
+    pub council_mint: Option<Pubkey>,
+    /// 理事会治理代币账户 公钥地址（可选）
+    pub council_token_account: Option<Pubkey>,
     /// Realm 的配置（含最大票权、最小治理权等参数）
     pub config: RealmConfig,
-    /// DAO 当前的治理权拥有者
-    pub authority: Pubkey,
+    /// DAO 当前的治理权拥有者，移除后为 None
+    pub authority: Option<Pubkey>,
 }
 
 impl Realm {
     /// 账户数据空间大小（单位：字节）
     /// 8   : Anchor账户判别符 (discriminator，自动添加)
+    /// 8   : id（u64）
     /// 4+32: name 字符串（4字节长度 + 最多32字节内容）
     /// 1   : account_type 枚举（u8）
     /// 32  : community_mint 公钥（Pubkey）
     /// 32  : community_token 公钥（Pubkey）
+    /// 33  : council_mint 可选公钥（Option<Pubkey>）
+    /// 33  : council_token 可选公钥（Option<Pubkey>）
     /// RealmConfig : 子配置结构体大小
     /// 33  : authority 可选公钥（Option<Pubkey>，1字节 tag + 32字节 pubkey）
-    pub const LEN: usize = 8 + 4 + 32 + 1 + 32 + 32 + 17 + 33;
+    pub const LEN: usize = 8 + 8 + 4 + Self::MAX_NAME_LEN + 1 + 32 + 32 + 33 + 33 + 17 + 33;
+    /// name 的最大字节数
+    pub const MAX_NAME_LEN: usize = 32;
     pub const REALM_SEEDS: &'static [u8] = b"realm";
+
+    /// 签名者是否为 Realm 当前的治理权拥有者
+    pub fn is_realm_authority(&self, signer: &Pubkey) -> bool {
+        self.authority == Some(*signer)
+    }
+
+    /// 是否为理事会代币 mint
+    pub fn is_council_mint(&self, mint: &Pubkey) -> bool {
+        self.council_mint == Some(*mint)
+    }
+
+    /// 是否为 Realm 的治理代币 mint（社区或理事会）
+    pub fn is_governing_token_mint(&self, mint: &Pubkey) -> bool {
+        self.community_mint == *mint || self.is_council_mint(mint)
+    }
+
+    /// 治理代币托管账户地址，社区与理事会代币分别托管
+    pub fn get_governing_token_holding(&self, mint: &Pubkey) -> Pubkey {
+        if self.is_council_mint(mint) {
+            self.council_token_account.unwrap_or_default()
+        } else {
+            self.community_token_account
+        }
+    }
+
+    /// 最大投票权来源，理事会代币始终按全部供应量计算
+    pub fn get_mint_max_voter_weight_source(&self, mint: &Pubkey) -> MintMaxVoterWeightSource {
+        if self.is_council_mint(mint) {
+            MintMaxVoterWeightSource::SupplyFraction(MintMaxVoterWeightSource::SUPPLY_FRACTION_BASE)
+        } else {
+            self.config.community_mint_max_voter_weight_source.clone()
+        }
+    }
 }
@@ -1,7 +1,9 @@
 //! 配置账户，用于配置DAO的配置信息
 use anchor_lang::prelude::*;
 
-use super::GovernanceAccountType;
+use crate::error::GovernanceError;
+
+use super::{GovernanceAccountType, MaxVoterWeightRecord, Realm, TokenOwnerRecord, VoterWeightAction, VoterWeightRecord};
 
 
 #[derive(Clone, Debug, AnchorSerialize, AnchorDeserialize)]
@@ -26,9 +28,10 @@ impl Default for Reserved110 {
 
 
 /// 治理代币类型，用于控制代币的流动性与回收权限
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub enum GoverningTokenType {
     /// 流动型代币，可随时存入与取出
+    #[default]
     Liquid,
     /// 成员制代币，不可主动取出，可由DAO回收
     Membership,
@@ -36,19 +39,29 @@ pub enum GoverningTokenType {
     Dormant
 }
 
-impl Default for GoverningTokenType {
-    fn default() -> Self {
-        Self::Liquid
-    }
-}
-
 /// 治理代币的配置，用于扩展代币的投票行为与治理规则
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct GoverningTokenConfig {
     /// 代币的类型
     pub token_type: GoverningTokenType,
     /// 有权锁定该代币功能的权限地址列表
-    pub lock_authorities: Vec<Pubkey>
+    pub lock_authorities: Vec<Pubkey>,
+    /// 投票权插件程序，配置后票权从插件的 VoterWeightRecord 读取
+    pub voter_weight_addin: Option<Pubkey>,
+    /// 最大票权插件程序，配置后最大票权从插件的 MaxVoterWeightRecord 读取
+    pub max_voter_weight_addin: Option<Pubkey>,
+}
+
+impl GoverningTokenConfig {
+    /// 锁定权限人数量不能超过账户预留空间
+    pub fn assert_is_valid(&self) -> Result<()> {
+        require!(
+            self.lock_authorities.len() <= RealmConfigAccount::MAX_LOCK_AUTHORITIES,
+            GovernanceError::TooManyLockAuthorities
+        );
+
+        Ok(())
+    }
 }
 
 /// Realm配置账户，用于存储社区与委员会治理代币的配置
@@ -60,6 +73,8 @@ pub struct RealmConfigAccount {
     pub realm: Pubkey,
     /// 社区代币配置
     pub community_token_config: GoverningTokenConfig,
+    /// 理事会代币配置
+    pub council_token_config: GoverningTokenConfig,
     /// 保留字段，用于未来扩展
     pub reserved: Reserved110,
 }
@@ -72,9 +87,72 @@ impl RealmConfigAccount {
     /// 110: 保留字段 (Reserved110, 64+32+14)
     /// 5: 社区代币配置 GoverningTokenConfig 的固定部分（不包含 lock_authorities 动态长度）
     /// 32*5: 最多可存 5 个权限管理者 (每个 Pubkey 32 字节)
+    /// 33*2: 投票权插件与最大票权插件 (Option<Pubkey>)
+    /// 理事会代币配置，同上
     /// 总计可支持 5 个 lock_authorities 成员
-    pub const LEN: usize = 8 + 1 + 32 + 110 + 5 + 32 * 5;
+    pub const LEN: usize = 8 + 1 + 32 + 110 + (5 + 32 * 5 + 33 * 2) * 2;
     pub const MAX_LOCK_AUTHORITIES: usize = 5;
     pub const REALM_CONFIG_SEEDS: &'static [u8] = b"realm_config";
     pub const COMMUNITY_TOKEN_SEEDS: &'static [u8] = b"community_token";
+    pub const COUNCIL_TOKEN_SEEDS: &'static [u8] = b"council_token";
+
+    /// 按 mint 获取对应的治理代币配置
+    pub fn get_token_config(&self, realm: &Realm, mint: &Pubkey) -> &GoverningTokenConfig {
+        if realm.is_council_mint(mint) {
+            &self.council_token_config
+        } else {
+            &self.community_token_config
+        }
+    }
+
+    /// 获取用户票权：配置了投票权插件时读取 VoterWeightRecord，否则使用存入的治理代币数量
+    pub fn resolve_voter_weight(
+        &self,
+        realm: &Realm,
+        token_owner_record: &TokenOwnerRecord,
+        voter_weight_record: Option<&AccountInfo>,
+        action: VoterWeightAction,
+        action_target: &Pubkey,
+    ) -> Result<u64> {
+        let mint = &token_owner_record.governing_token_mint;
+        let Some(addin) = self.get_token_config(realm, mint).voter_weight_addin else {
+            return Ok(token_owner_record.governing_token_deposit_amount);
+        };
+
+        let record_info = voter_weight_record.ok_or(GovernanceError::MissingVoterWeightRecord)?;
+        let record = VoterWeightRecord::deserialize_checked(record_info, &addin)?;
+
+        require!(
+            record.realm == token_owner_record.realm
+                && record.governing_token_mint == *mint
+                && record.governing_token_owner == token_owner_record.governing_token_owner,
+            GovernanceError::InvalidVoterWeightRecord
+        );
+        record.assert_is_valid(action, action_target)?;
+
+        Ok(record.voter_weight)
+    }
+
+    /// 配置了最大票权插件时读取 MaxVoterWeightRecord；未配置返回 None，由调用方按代币供应量计算
+    pub fn resolve_max_voter_weight_from_addin(
+        &self,
+        realm: &Realm,
+        mint: &Pubkey,
+        max_voter_weight_record: Option<&AccountInfo>,
+    ) -> Result<Option<u128>> {
+        let Some(addin) = self.get_token_config(realm, mint).max_voter_weight_addin else {
+            return Ok(None);
+        };
+
+        let record_info = max_voter_weight_record.ok_or(GovernanceError::MissingMaxVoterWeightRecord)?;
+        let record = MaxVoterWeightRecord::deserialize_checked(record_info, &addin)?;
+
+        require!(
+            record.realm == self.realm && record.governing_token_mint == *mint,
+            GovernanceError::InvalidMaxVoterWeightRecord
+        );
+        record.assert_is_valid()?;
+
+        Ok(Some(u128::from(record.max_voter_weight)))
+    }
 }
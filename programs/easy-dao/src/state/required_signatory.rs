@@ -12,10 +12,12 @@ pub struct RequiredSignatory {
     pub governance: Pubkey,
     /// 签名者
     pub signatory: Pubkey,
+    /// 支付账户租金的地址，关闭账户时退还给它
+    pub rent_payer: Pubkey,
 }
 
 
 impl RequiredSignatory {
-    pub const LEN: usize = 8 + 1 + 32 + 32;
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 32;
     pub const REQUIRED_SIGNATORY_SEED: &'static [u8] = b"required_signatory";
 }
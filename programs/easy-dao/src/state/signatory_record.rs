@@ -13,11 +13,13 @@ pub struct SignatoryRecord {
     /// 签名者地址
     pub signatory: Pubkey,
     /// 是否签署了提案
-    pub signed_off: bool
+    pub signed_off: bool,
+    /// 支付账户租金的地址，关闭账户时退还给它
+    pub rent_payer: Pubkey,
 }
 
 
 impl SignatoryRecord {
-    pub const LEN: usize = 8 + 1 + 32 + 32 + 1;
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 1 + 32;
     pub const SIGNATORY_RECORD_SEED: &'static [u8] = b"signatory_record";
 }
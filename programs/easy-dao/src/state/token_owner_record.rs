@@ -40,7 +40,9 @@ pub struct TokenOwnerRecord {
     /// 对用户治理代币存款的外部锁列表（如插件、质押等设置的锁定）
     pub locks: Vec<TokenOwnerRecordLock>,
     /// 提案索引累加器
-    pub proposal_index: u64
+    pub proposal_index: u64,
+    /// 支付账户租金的地址，关闭账户时退还给它
+    pub rent_payer: Pubkey,
 }
 
 
@@ -49,8 +51,14 @@ impl TokenOwnerRecord {
     pub const TOKEN_OWNER_RECORD_LAYOUT_VERSION: u8 = 1;
     pub const MAX_LOCKS: usize = 5;
     /// TokenOwnerRecord 的序列化长度（单位：字节）
-    /// 包含基本治理信息、可选代理地址，以及最多 MAX_LOCKS 个锁记录（每个 41 字节）
-    pub const LEN: usize = 8 + 1 + 32 * 3 + 8 * 2 + 2 + 33 + 8 + 4 + Self::MAX_LOCKS * 41;
+    /// 包含基本治理信息、可选代理地址、租金支付人，以及最多 MAX_LOCKS 个锁记录（每个 41 字节）
+    pub const LEN: usize = 8 + 1 + 32 * 3 + 8 * 2 + 2 + 33 + 8 + 32 + 4 + Self::MAX_LOCKS * 41;
+
+    /// 签名者是否为代币拥有者或其治理代理人
+    pub fn is_token_owner_or_delegate(&self, signer: &Pubkey) -> bool {
+        self.governing_token_owner == *signer
+            || self.governance_delegate == Some(*signer)
+    }
 
     pub fn decrease_outstanding_proposal_count(&mut self) -> Result<()> {
         if self.outstanding_proposal_count != 0 {
@@ -60,4 +68,85 @@ impl TokenOwnerRecord {
         }
         Ok(())
     }
+
+    /// 设置锁：先清理已过期的锁，同一 (lock_id, authority) 的锁会被覆盖
+    pub fn upsert_lock(&mut self, lock: TokenOwnerRecordLock) -> Result<()> {
+        let now: u64 = Clock::get()?.unix_timestamp.try_into()?;
+        require!(lock.expiry > now, GovernanceError::ExpiredTokenOwnerRecordLock);
+
+        self.locks.retain(|l| {
+            l.expiry > now && !(l.lock_id == lock.lock_id && l.authority == lock.authority)
+        });
+
+        require!(
+            self.locks.len() < Self::MAX_LOCKS,
+            GovernanceError::TooManyTokenOwnerRecordLocks
+        );
+
+        self.locks.push(lock);
+
+        Ok(())
+    }
+
+    /// 移除指定权限人设置的锁，顺带清理已过期的锁
+    pub fn remove_lock(&mut self, lock_id: u8, authority: &Pubkey) -> Result<()> {
+        let now: u64 = Clock::get()?.unix_timestamp.try_into()?;
+
+        let position = self.locks
+            .iter()
+            .position(|l| l.lock_id == lock_id && l.authority == *authority)
+            .ok_or(GovernanceError::TokenOwnerRecordLockNotFound)?;
+        self.locks.remove(position);
+
+        self.locks.retain(|l| l.expiry > now);
+
+        Ok(())
+    }
+
+    /// 取出治理代币前的校验：投票权重已快照进 VoteRecord，
+    /// 存在未释放投票时不允许减少存款，防止取出后转移到其他钱包重复投票
+    pub fn assert_can_withdraw_governing_tokens(&self) -> Result<()> {
+        require!(
+            self.unrelinquished_votes_count == 0,
+            GovernanceError::AllVotesMustBeRelinquishedToWithdrawGoverningTokens
+        );
+
+        require!(
+            self.outstanding_proposal_count == 0,
+            GovernanceError::AllProposalsMustBeFinalisedToWithdrawGoverningTokens
+        );
+
+        self.assert_has_no_active_locks()
+    }
+
+    /// 校验记录可以关闭：无存款、无未释放投票、无未结束提案且无有效锁
+    /// 创建过提案的记录不能关闭：提案 PDA 以 proposal_index 派生，重建后序号归零会与已有提案冲突，
+    /// 且押金退还、交易失败标记等指令在提案结束后仍需读取该记录
+    pub fn assert_can_close(&self) -> Result<()> {
+        require!(
+            self.governing_token_deposit_amount == 0
+                && self.unrelinquished_votes_count == 0
+                && self.outstanding_proposal_count == 0,
+            GovernanceError::TokenOwnerRecordNotEmpty
+        );
+
+        require!(
+            self.proposal_index == 0,
+            GovernanceError::TokenOwnerRecordHasProposals
+        );
+
+        self.assert_has_no_active_locks()
+    }
+
+    /// 校验不存在未过期的锁（`u64::MAX` 视为永久锁）
+    pub fn assert_has_no_active_locks(&self) -> Result<()> {
+        let now: u64 = Clock::get()?.unix_timestamp.try_into()?;
+
+        require!(
+            !self.locks.iter().any(|lock| lock.expiry > now),
+            GovernanceError::TokenOwnerRecordLocked
+        );
+
+        Ok(())
+    }
 }
@@ -1,15 +1,31 @@
 //! 投票记录账户
 use anchor_lang::prelude::*;
 
-use super::GovernanceAccountType;
+use super::{GovernanceAccountType, Proposal};
+
+
+/// 投票人对单个选项的选择
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct VoteChoice {
+    /// 排名（排名投票暂未支持，必须为 0）
+    pub rank: u8,
+    /// 计入该选项的票权百分比，0 表示未选择，100 表示选择
+    pub weight_percentage: u8,
+}
 
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
 pub enum Vote {
-    /// 是
+    /// 是，等同于单选提案的唯一选项
     Yes,
     /// 否
-    No
+    No,
+    /// 弃权，计入参与度但不计入通过门槛
+    Abstain,
+    /// 否决，由理事会代币持有人对社区提案投出
+    Veto,
+    /// 按选项投票，长度需与提案选项数量一致
+    Approve(Vec<VoteChoice>)
 }
 
 
@@ -23,15 +39,18 @@ pub struct VoteRecord {
     pub governing_token_owner: Pubkey,
     /// 是否已释放
     pub is_relinquished: bool,
-    /// 投票权重
+    /// 投票时快照的票权，投票期间撤回时按此从计票中扣除
     pub vote_weight: u64,
+    /// 支付账户租金的地址，关闭账户时退还给它
+    pub rent_payer: Pubkey,
     /// 投票结果
     pub vote: Vote,
 }
 
 
 impl VoteRecord {
-    pub const LEN: usize = 8 + 1 + 32 + 32 + 1 + 8 + 1;
+    /// vote 字段按最多 MAX_OPTIONS 个选择预留：1 + 4 + 2 * MAX_OPTIONS
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 1 + 8 + 32 + 1 + 4 + 2 * Proposal::MAX_OPTIONS;
     pub const VOTERECORD_SEED: &'static [u8] = b"vote_record";
 }
 
@@ -0,0 +1,123 @@
+//! 投票权插件（voter weight add-in）账户
+//! 布局与 spl-governance-addin-api 保持一致，由插件程序创建和维护
+use anchor_lang::prelude::*;
+
+use crate::error::GovernanceError;
+
+
+/// 插件计算出的票权适用的操作
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum VoterWeightAction {
+    /// 投票
+    CastVote,
+    /// 评论提案
+    CommentProposal,
+    /// 创建治理账户
+    CreateGovernance,
+    /// 创建提案
+    CreateProposal,
+    /// 签署提案
+    SignOffProposal,
+}
+
+
+/// 用户票权记录（不含 8 字节判别符）
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VoterWeightRecord {
+    /// 所属 Realm
+    pub realm: Pubkey,
+    /// 治理代币 mint
+    pub governing_token_mint: Pubkey,
+    /// 治理代币拥有者
+    pub governing_token_owner: Pubkey,
+    /// 插件计算出的票权
+    pub voter_weight: u64,
+    /// 票权过期的 slot，None 表示不过期
+    pub voter_weight_expiry: Option<u64>,
+    /// 票权适用的操作，None 表示适用所有操作
+    pub weight_action: Option<VoterWeightAction>,
+    /// 票权适用的目标账户（如提案），None 表示不限
+    pub weight_action_target: Option<Pubkey>,
+    /// 保留字段
+    pub reserved: [u8; 8],
+}
+
+impl VoterWeightRecord {
+    /// sha256("account:VoterWeightRecord")[..8]
+    pub const DISCRIMINATOR: [u8; 8] = [46, 249, 155, 75, 153, 248, 116, 9];
+
+    /// 校验账户归属插件程序并反序列化
+    pub fn deserialize_checked(record_info: &AccountInfo, addin: &Pubkey) -> Result<Self> {
+        require_keys_eq!(*record_info.owner, *addin, GovernanceError::InvalidVoterWeightRecord);
+
+        let data = record_info.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == Self::DISCRIMINATOR,
+            GovernanceError::InvalidVoterWeightRecord
+        );
+
+        Self::deserialize(&mut &data[8..])
+            .map_err(|_| error!(GovernanceError::InvalidVoterWeightRecord))
+    }
+
+    /// 校验未过期，且适用于指定操作和目标
+    pub fn assert_is_valid(&self, action: VoterWeightAction, target: &Pubkey) -> Result<()> {
+        if let Some(expiry) = self.voter_weight_expiry {
+            require!(expiry >= Clock::get()?.slot, GovernanceError::VoterWeightRecordExpired);
+        }
+
+        if let Some(weight_action) = &self.weight_action {
+            require!(*weight_action == action, GovernanceError::InvalidVoterWeightAction);
+        }
+
+        if let Some(weight_action_target) = self.weight_action_target {
+            require_keys_eq!(weight_action_target, *target, GovernanceError::InvalidVoterWeightAction);
+        }
+
+        Ok(())
+    }
+}
+
+
+/// 最大票权记录（不含 8 字节判别符）
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MaxVoterWeightRecord {
+    /// 所属 Realm
+    pub realm: Pubkey,
+    /// 治理代币 mint
+    pub governing_token_mint: Pubkey,
+    /// 插件计算出的最大票权
+    pub max_voter_weight: u64,
+    /// 最大票权过期的 slot，None 表示不过期
+    pub max_voter_weight_expiry: Option<u64>,
+    /// 保留字段
+    pub reserved: [u8; 8],
+}
+
+impl MaxVoterWeightRecord {
+    /// sha256("account:MaxVoterWeightRecord")[..8]
+    pub const DISCRIMINATOR: [u8; 8] = [157, 95, 242, 151, 16, 98, 26, 118];
+
+    /// 校验账户归属插件程序并反序列化
+    pub fn deserialize_checked(record_info: &AccountInfo, addin: &Pubkey) -> Result<Self> {
+        require_keys_eq!(*record_info.owner, *addin, GovernanceError::InvalidMaxVoterWeightRecord);
+
+        let data = record_info.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == Self::DISCRIMINATOR,
+            GovernanceError::InvalidMaxVoterWeightRecord
+        );
+
+        Self::deserialize(&mut &data[8..])
+            .map_err(|_| error!(GovernanceError::InvalidMaxVoterWeightRecord))
+    }
+
+    /// 校验未过期
+    pub fn assert_is_valid(&self) -> Result<()> {
+        if let Some(expiry) = self.max_voter_weight_expiry {
+            require!(expiry >= Clock::get()?.slot, GovernanceError::MaxVoterWeightRecordExpired);
+        }
+
+        Ok(())
+    }
+}
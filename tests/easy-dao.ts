@@ -3,8 +3,17 @@ import path from "path";
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { EasyDao } from "../target/types/easy_dao";
-import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
-import { getMint, createMint, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo
+} from "@solana/spl-token";
 
 describe("easy-dao", () => {
   const provider = anchor.AnchorProvider.env();
@@ -22,8 +31,21 @@ describe("easy-dao", () => {
   let communityTokenPda: PublicKey;
   let users: Keypair[];
 
+  /** 社区代币有 6 位小数，存款与票权均以最小单位计，1 枚代币为 10^6 **/
+  const ONE_TOKEN = 1_000_000;
+
   const id = new anchor.BN(1);
 
+  /** 配置了锁定权限人的 Realm，用于锁定、Realm 配置与插件测试 **/
+  const lockRealmId = new anchor.BN(3);
+  let lockRealmPda: PublicKey;
+  let lockRealmGovernance: PublicKey;
+
+  /** 带理事会代币的 Realm，用于理事会投票、否决等测试 **/
+  const councilRealmId = new anchor.BN(2);
+  let councilRealmPda: PublicKey;
+  let councilMint: PublicKey;
+
   const CACHE_PATH = path.resolve(__dirname, "addresses.json");
 
   const USERS_KEYPAIR_PATH = path.resolve(__dirname, "users_secret.json");
@@ -61,6 +83,507 @@ describe("easy-dao", () => {
     return JSON.parse(fs.readFileSync(CACHE_PATH, "utf8"));
   }
 
+  const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+  /** 链上当前的 unix 时间戳（秒） **/
+  async function getChainTime(): Promise<number> {
+    const slot = await connection.getSlot("confirmed");
+    return (await connection.getBlockTime(slot)) ?? Math.floor(Date.now() / 1000);
+  }
+
+  /** 等待链上时间超过指定时间戳，用于等待投票期、草稿期等结束 **/
+  async function waitUntilChainTime(unixTimestamp: number) {
+    while ((await getChainTime()) <= unixTimestamp) {
+      await sleep(1000);
+    }
+  }
+
+  /** 断言交易因指定的程序错误失败 **/
+  async function expectAnchorError(promise: Promise<any>, code: string) {
+    try {
+      await promise;
+    } catch (err: any) {
+      // 直接发送的原始交易没有被解析，从日志中解析程序错误
+      const actual = err?.error?.errorCode?.code
+        ?? anchor.AnchorError.parse(err?.logs ?? [])?.error.errorCode.code;
+      if (actual !== code) {
+        throw new Error(`❌ 期望错误 ${code}, got: ${actual ?? err.message}`);
+      }
+      return;
+    }
+    throw new Error(`❌ 期望错误 ${code}, 但交易成功`);
+  }
+
+  function getTokenOwnerRecordPda(owner: PublicKey, governingMint: PublicKey = mint, realm: PublicKey = realmPda) {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("governance"),
+        realm.toBuffer(),
+        governingMint.toBuffer(),
+        owner.toBuffer()
+      ],
+      program.programId
+    )[0];
+  }
+
+  function getProposalPda(governance: PublicKey, tokenOwnerRecord: PublicKey, index: anchor.BN) {
+    return PublicKey.findProgramAddressSync(
+      [
+        governance.toBuffer(),
+        tokenOwnerRecord.toBuffer(),
+        index.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    )[0];
+  }
+
+  function getProposalDepositPda(depositPayer: PublicKey, proposal: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("proposal-deposit"),
+        depositPayer.toBuffer(),
+        proposal.toBuffer()
+      ],
+      program.programId
+    )[0];
+  }
+
+  function getVoteRecordPda(proposal: PublicKey, tokenOwnerRecord: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vote_record"),
+        proposal.toBuffer(),
+        tokenOwnerRecord.toBuffer()
+      ],
+      program.programId
+    )[0];
+  }
+
+  function getNativeTreasuryPda(governance: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("native-treasury"), governance.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  function getProposalTransactionPda(proposal: PublicKey, optionIndex: number, transactionIndex: number) {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("proposal_transaction"),
+        proposal.toBuffer(),
+        Buffer.from([optionIndex]),
+        new anchor.BN(transactionIndex).toArrayLike(Buffer, "le", 2)
+      ],
+      program.programId
+    )[0];
+  }
+
+  const TREASURY_TRANSFER_LAMPORTS = 1_000_000;
+
+  /** 从治理原生金库转账的提案指令；账户中带上 System Program，执行时由 remainingAccounts 一并传入 **/
+  function buildTreasuryTransferInstruction(governance: PublicKey, recipient: PublicKey) {
+    const ix = SystemProgram.transfer({
+      fromPubkey: getNativeTreasuryPda(governance),
+      toPubkey: recipient,
+      lamports: TREASURY_TRANSFER_LAMPORTS,
+    });
+    return {
+      programId: ix.programId,
+      data: ix.data,
+      accounts: ix.keys.map(key => ({ pubkey: key.pubkey, isSigner: key.isSigner, isWritable: key.isWritable })),
+    };
+  }
+
+  /** 执行提案交易时的 remainingAccounts：目标程序账户在前，随后是指令账户；PDA 签名由程序代签，客户端不标记签名 **/
+  function getRemainingAccounts(instructionData: { programId: PublicKey, accounts: { pubkey: PublicKey, isWritable: boolean }[] }) {
+    return [
+      { pubkey: instructionData.programId, isSigner: false, isWritable: false },
+      ...instructionData.accounts.map(account => ({
+        pubkey: account.pubkey,
+        isSigner: false,
+        isWritable: account.isWritable,
+      })),
+    ];
+  }
+
+  /** 创建并充值治理原生金库 **/
+  async function fundNativeTreasury(governance: PublicKey, lamports: number) {
+    await program.methods.createNativeTreasury()
+      .accounts({
+        payer,
+        governance,
+        nativeTreasury: getNativeTreasuryPda(governance),
+      } as any)
+      .rpc();
+    await provider.sendAndConfirm(new Transaction().add(
+      SystemProgram.transfer({
+        fromPubkey: payer,
+        toPubkey: getNativeTreasuryPda(governance),
+        lamports,
+      })
+    ));
+  }
+
+  function getRealmPda(realmId: anchor.BN) {
+    return PublicKey.findProgramAddressSync(
+      [realmId.toArrayLike(Buffer, "le", 8), Buffer.from("realm")],
+      program.programId
+    )[0];
+  }
+
+  function getCommunityTokenPda(governingMint: PublicKey, realm: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [governingMint.toBuffer(), realm.toBuffer(), Buffer.from("community_token")],
+      program.programId
+    )[0];
+  }
+
+  function getCouncilTokenPda(councilMint: PublicKey, realm: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [councilMint.toBuffer(), realm.toBuffer(), Buffer.from("council_token")],
+      program.programId
+    )[0];
+  }
+
+  /** 以 payer 为治理权拥有者创建只使用社区代币的 Realm **/
+  async function createCommunityRealm(realmId: anchor.BN, lockAuthorities: PublicKey[] = []): Promise<PublicKey> {
+    await program.methods.createRealm(
+      realmId,
+      `测试${realmId.toString()}`,
+      {
+        minCommunityWeightToCreateGovernance: new anchor.BN(1),
+        communityMintMaxVoterWeightSource: { supplyFraction: [new anchor.BN(10_000_000)] },
+      } as any,
+      { tokenType: { liquid: {} }, lockAuthorities, voterWeightAddin: null, maxVoterWeightAddin: null } as any,
+      { tokenType: { liquid: {} }, lockAuthorities: [], voterWeightAddin: null, maxVoterWeightAddin: null } as any
+    ).accounts({
+      authority: payer,
+      mint: mint,
+      councilMint: null,
+      councilTokenAccount: null,
+      tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID
+    } as any).rpc();
+    return getRealmPda(realmId);
+  }
+
+  /** 创建 TokenOwnerRecord 并存入治理代币 **/
+  async function createTokenOwnerRecordAndDeposit(
+    user: Keypair,
+    governingMint: PublicKey,
+    realm: PublicKey,
+    governingTokenHolding: PublicKey,
+    amount: number
+  ) {
+    await program.methods.createTokenOwnerRecord()
+      .accounts({
+        user: user.publicKey,
+        mint: governingMint,
+        realm,
+      } as any)
+      .signers([user])
+      .rpc();
+    await program.methods.depositGoverningTokens(new anchor.BN(amount))
+      .accounts({
+        user: user.publicKey,
+        mint: governingMint,
+        realm,
+        governingTokenHolding,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+      } as any)
+      .signers([user])
+      .rpc();
+  }
+
+  /** 测试用治理配置，按需覆盖个别字段 **/
+  function buildGovernanceConfig(overrides: Record<string, any> = {}) {
+    return {
+      communityVoteThreshold: { yesVotePercentage: [10] },
+      communityVoteTipping: { early: {} },
+      minCommunityWeightToCreateProposal: new anchor.BN(1),
+      councilVoteThreshold: { disabled: {} },
+      councilVoteTipping: { strict: {} },
+      minCouncilWeightToCreateProposal: new anchor.BN(1),
+      councilVetoVoteThreshold: { disabled: {} },
+      transactionsHoldUpTime: 0,
+      votingBaseTime: 120,
+      votingCoolOffTime: 60,
+      depositExemptProposalCount: 0,
+      proposalDepositAmount: new anchor.BN(100_000_000),
+      proposalDepositType: { sol: {} },
+      depositSlashTurnoutPercentage: null,
+      maxDraftTime: 86_400,
+      transactionRetryTime: 3_600,
+      ...overrides,
+    } as any;
+  }
+
+  /** 以 Realm 管理员身份创建 Governance，被治理账户随机生成，避免与已有 Governance 冲突 **/
+  async function createGovernance(config: any, realm: PublicKey = realmPda): Promise<PublicKey> {
+    const governedAccount = Keypair.generate().publicKey;
+    const [governancePda] = PublicKey.findProgramAddressSync(
+      [realm.toBuffer(), Buffer.from("governance"), governedAccount.toBuffer()],
+      program.programId
+    );
+    await program.methods.createGovernance(config)
+      .accounts({
+        authority: payer,
+        realm,
+        governedAccount,
+        tokenOwnerRecord: null,
+        voterWeightRecord: null,
+      } as any)
+      .rpc();
+    return governancePda;
+  }
+
+  /** 创建提案，提案地址按发起人 TokenOwnerRecord 当前的 proposalIndex 派生 **/
+  async function createProposal(
+    user: Keypair,
+    governance: PublicKey,
+    opts: {
+      governingMint?: PublicKey,
+      realm?: PublicKey,
+      voteType?: any,
+      labels?: string[],
+      /** 覆盖默认账户，如押金代币账户、VoterWeightRecord **/
+      accounts?: Record<string, any>,
+    } = {}
+  ): Promise<PublicKey> {
+    const governingMint = opts.governingMint ?? mint;
+    const realm = opts.realm ?? realmPda;
+    const tokenOwnerRecordPda = getTokenOwnerRecordPda(user.publicKey, governingMint, realm);
+    const tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+    const proposalPda = getProposalPda(governance, tokenOwnerRecordPda, tokenOwnerRecordAccount.proposalIndex);
+
+    await program.methods.createProposal(
+      "测试提案",
+      "https://example.com",
+      opts.voteType ?? { singleChoice: {} },
+      opts.labels ?? ["通过"]
+    ).accounts({
+      mint: governingMint,
+      authority: user.publicKey,
+      realm,
+      governance,
+      tokenOwnerRecord: tokenOwnerRecordPda,
+      proposal: proposalPda,
+      voterWeightRecord: null,
+      proposerTokenAccount: null,
+      depositTokenAccount: null,
+      tokenProgram: null,
+      associatedTokenProgram: null,
+      ...(opts.accounts ?? {}),
+    } as any).signers([user]).rpc();
+
+    return proposalPda;
+  }
+
+  /** 没有签署人的提案由发起人直接签署，进入投票 **/
+  async function signOffProposal(
+    user: Keypair,
+    governance: PublicKey,
+    proposal: PublicKey,
+    governingMint: PublicKey = mint,
+    realm: PublicKey = realmPda
+  ) {
+    await program.methods.signOffProposal()
+      .accounts({
+        proposal,
+        governance,
+        realm,
+        signatory: user.publicKey,
+        tokenOwnerRecord: getTokenOwnerRecordPda(user.publicKey, governingMint, realm),
+        signatoryRecord: null,
+      } as any)
+      .signers([user])
+      .rpc();
+  }
+
+  /** 发起人取消提案 **/
+  async function cancelProposal(
+    user: Keypair,
+    governance: PublicKey,
+    proposal: PublicKey,
+    governingMint: PublicKey = mint,
+    realm: PublicKey = realmPda
+  ) {
+    await program.methods.cancelProposal()
+      .accounts({
+        authority: user.publicKey,
+        realm,
+        governance,
+        tokenOwnerRecord: getTokenOwnerRecordPda(user.publicKey, governingMint, realm),
+        proposal,
+      } as any)
+      .signers([user])
+      .rpc();
+  }
+
+  /** 提案结束后退还押金，押金账户由押金支付人与提案派生；accounts 用于传入金库、代币账户等 **/
+  async function refundProposalDeposit(
+    depositPayer: PublicKey,
+    governance: PublicKey,
+    proposal: PublicKey,
+    accounts: Record<string, any> = {}
+  ) {
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    const governanceAccount = await program.account.governance.fetch(governance);
+    await program.methods.refundProposalDeposit()
+      .accounts({
+        proposal,
+        proposalDeposit: getProposalDepositPda(depositPayer, proposal),
+        realm: governanceAccount.realm,
+        mint: proposalAccount.governingTokenMint,
+        governance,
+        tokenOwnerRecord: proposalAccount.tokenOwnerRecord,
+        depositPayer,
+        nativeTreasury: null,
+        depositTokenAccount: null,
+        depositPayerTokenAccount: null,
+        treasuryTokenAccount: null,
+        tokenProgram: null,
+        ...accounts,
+      } as any)
+      .rpc();
+  }
+
+  /** 投票，voteMint 为投票人使用的治理代币（否决票使用理事会代币） **/
+  async function castVote(
+    voter: Keypair,
+    governance: PublicKey,
+    proposal: PublicKey,
+    vote: any,
+    voteMint: PublicKey = mint,
+    realm: PublicKey = realmPda
+  ) {
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    const tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(proposalAccount.tokenOwnerRecord);
+    const voteTokenOwnerRecordPda = getTokenOwnerRecordPda(voter.publicKey, voteMint, realm);
+
+    await program.methods.castVote(vote)
+      .accounts({
+        proposal,
+        governance,
+        realm,
+        mint: voteMint,
+        authority: voter.publicKey,
+        user: tokenOwnerRecordAccount.governingTokenOwner,
+        voteTokenOwnerRecord: voteTokenOwnerRecordPda,
+        tokenOwnerRecord: proposalAccount.tokenOwnerRecord,
+        voteRecord: getVoteRecordPda(proposal, voteTokenOwnerRecordPda),
+        voterWeightRecord: null,
+        maxVoterWeightRecord: null,
+      } as any)
+      .signers([voter])
+      .rpc();
+  }
+
+  /** 多选提案的认可投票，selected 为选中的选项索引 **/
+  function approveVote(optionsCount: number, selected: number[]) {
+    return {
+      approve: [
+        Array.from({ length: optionsCount }, (_, i) => ({
+          rank: 0,
+          weightPercentage: selected.includes(i) ? 100 : 0,
+        })),
+      ],
+    };
+  }
+
+  /** 撤回投票，voteMint 为投票时使用的治理代币 **/
+  async function relinquishVote(
+    voter: Keypair,
+    governance: PublicKey,
+    proposal: PublicKey,
+    voteMint: PublicKey = mint,
+    realm: PublicKey = realmPda
+  ) {
+    const voteTokenOwnerRecordPda = getTokenOwnerRecordPda(voter.publicKey, voteMint, realm);
+    await program.methods.relinquishVote()
+      .accounts({
+        proposal,
+        governance,
+        realm,
+        signer: voter.publicKey,
+        tokenOwnerRecord: voteTokenOwnerRecordPda,
+        voteRecord: getVoteRecordPda(proposal, voteTokenOwnerRecordPda),
+        rentPayer: voter.publicKey,
+      } as any)
+      .signers([voter])
+      .rpc();
+  }
+
+  /** 由提案发起人向提案选项添加一条交易 **/
+  async function addTransaction(
+    user: Keypair,
+    governance: PublicKey,
+    proposal: PublicKey,
+    instructionData: any,
+    holdUpTime: number = 0,
+    transactionIndex: number = 0,
+    optionIndex: number = 0
+  ) {
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    await program.methods.addTransaction(optionIndex, transactionIndex, holdUpTime, [instructionData])
+      .accounts({
+        governance,
+        proposal,
+        authority: user.publicKey,
+        tokenOwnerRecord: proposalAccount.tokenOwnerRecord,
+      } as any)
+      .signers([user])
+      .rpc();
+    return getProposalTransactionPda(proposal, optionIndex, transactionIndex);
+  }
+
+  /** 执行提案交易，remainingAccounts 按交易中的指令账户传入 **/
+  async function executeTransaction(
+    governance: PublicKey,
+    proposal: PublicKey,
+    instructionData: any,
+    transactionIndex: number = 0,
+    optionIndex: number = 0
+  ) {
+    await program.methods.executeTransaction()
+      .accounts({
+        proposal,
+        governance,
+        proposalTransaction: getProposalTransactionPda(proposal, optionIndex, transactionIndex),
+      } as any)
+      .remainingAccounts(getRemainingAccounts(instructionData))
+      .rpc();
+  }
+
+  /** 投票期结束后结束投票 **/
+  async function finalizeVote(governance: PublicKey, proposal: PublicKey, realm: PublicKey = realmPda) {
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    const tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(proposalAccount.tokenOwnerRecord);
+    await program.methods.finalizeVote()
+      .accounts({
+        proposal,
+        governance,
+        realm,
+        mint: proposalAccount.governingTokenMint,
+        user: tokenOwnerRecordAccount.governingTokenOwner,
+        tokenOwnerRecord: proposalAccount.tokenOwnerRecord,
+        maxVoterWeightRecord: null,
+      } as any)
+      .rpc();
+  }
+
+  /** 等待提案投票期（含冷却期）结束 **/
+  async function waitForVotingEnd(governance: PublicKey, proposal: PublicKey) {
+    const governanceAccount = await program.account.governance.fetch(governance);
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    // 多选提案没有冷却期
+    const coolOffTime = "multiChoice" in proposalAccount.voteType ? 0 : governanceAccount.config.votingCoolOffTime;
+    await waitUntilChainTime(
+      proposalAccount.votingStartedAt.toNumber()
+        + governanceAccount.config.votingBaseTime
+        + coolOffTime
+    );
+  }
+
   async function createEnvironment() {
     users = [];
     for (let i = 0; i < 20; i++) {
@@ -141,7 +664,11 @@ describe("easy-dao", () => {
     } catch (_) { /* 文件不存在时继续创建环境 */ }
 
     const cached = loadCache();
-    if (cached && usersLoaded) {
+    // 每次 anchor test 都会启动新的本地验证节点，缓存的 mint 不存在时需重新初始化
+    const cachedMintInfo = cached
+      ? await connection.getAccountInfo(new PublicKey(cached.mint))
+      : null;
+    if (cached && usersLoaded && cachedMintInfo) {
       // assign cached pubkeys
       realmConfigPda    = new PublicKey(cached.realmConfigPda);
       realmPda  = new PublicKey(cached.realmPda);
@@ -165,19 +692,24 @@ describe("easy-dao", () => {
     // (2) GoverningTokenConfig —— 不用插件、固定 5 个空位的锁权限人
     const governingTokenConfig = {
       tokenType: { liquid: {} },  // 流动型代币
-      lockAuthorities: []         // 默认为 0,
+      lockAuthorities: [],        // 默认为 0,
+      voterWeightAddin: null,     // 不使用投票权插件
+      maxVoterWeightAddin: null,
     } as any;
 
     const tx = await program.methods.createRealm(
       id,
       "测试",
       realmConfig,
+      governingTokenConfig,
       governingTokenConfig
     ).accounts({
       authority: payer,
       mint: mint,
+      councilMint: null,
+      councilTokenAccount: null,
       tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID
-    }).rpc();
+    } as any).rpc();
 
     console.log("initialized success", tx);
 
@@ -246,28 +778,114 @@ describe("easy-dao", () => {
   it("deposit governing tokens", async () => {
     for (const user of users) {
       const tx = await program.methods.depositGoverningTokens(
-        new anchor.BN(20)
+        new anchor.BN(20 * ONE_TOKEN)
       )
         .accounts({
           user: user.publicKey,
           mint: mint,
           realm: realmPda,
+          governingTokenHolding: communityTokenPda,
           tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
-        })
+        } as any)
         .signers([user])
         .rpc();
       console.log(`✅ deposit governing tokens for ${user.publicKey.toBase58()} success`, tx);
     }
   });
 
+  it("withdraw governing tokens", async () => {
+    const user = users[19];
+    const tx = await program.methods.withdrawGoverningTokens(
+      new anchor.BN(5 * ONE_TOKEN)
+    )
+      .accounts({
+        user: user.publicKey,
+        mint: mint,
+        realm: realmPda,
+        governingTokenHolding: communityTokenPda,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+      } as any)
+      .signers([user])
+      .rpc();
+    console.log(`✅ withdraw governing tokens for ${user.publicKey.toBase58()} success`, tx);
+
+    const [tokenOwnerRecordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("governance"),
+        realmPda.toBuffer(),
+        mint.toBuffer(),
+        user.publicKey.toBuffer()
+      ],
+      program.programId
+    );
+    const tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+    if (!tokenOwnerRecordAccount.governingTokenDepositAmount.eq(new anchor.BN(15 * ONE_TOKEN))) {
+      throw new Error("❌ governingTokenDepositAmount 应为 15");
+    }
+    console.log("✅ withdraw governing tokens 校验通过！");
+  });
+
+  it("set and clear governance delegate", async () => {
+    const user = users[18];
+    const delegate = users[17].publicKey;
+    const [tokenOwnerRecordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("governance"),
+        realmPda.toBuffer(),
+        mint.toBuffer(),
+        user.publicKey.toBuffer()
+      ],
+      program.programId
+    );
+
+    await program.methods.setGovernanceDelegate(delegate)
+      .accounts({
+        authority: user.publicKey,
+        tokenOwnerRecord: tokenOwnerRecordPda,
+      } as any)
+      .signers([user])
+      .rpc();
+
+    let tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+    if (!tokenOwnerRecordAccount.governanceDelegate?.equals(delegate)) {
+      throw new Error("❌ governanceDelegate 不匹配");
+    }
+
+    // 代理人也可以清除代理
+    await program.methods.setGovernanceDelegate(null)
+      .accounts({
+        authority: delegate,
+        tokenOwnerRecord: tokenOwnerRecordPda,
+      } as any)
+      .signers([users[17]])
+      .rpc();
+
+    tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+    if (tokenOwnerRecordAccount.governanceDelegate) {
+      throw new Error("❌ governanceDelegate 应为空");
+    }
+    console.log("✅ set governance delegate 校验通过！");
+  });
+
   it("create governance", async () => {
     // 1. 构造 GovernanceConfig（字段需与 IDL 一致）
     const governanceConfig = {
       communityVoteThreshold: { yesVotePercentage: [10] },            // u8
+      communityVoteTipping: { early: {} },                          // 达到门槛即提前通过
       minCommunityWeightToCreateProposal: new anchor.BN(1),         // u64
-      transactionsHoldUpTime:            new anchor.BN(0),          // u32
-      votingBaseTime:                    new anchor.BN(120),        // u32
-      votingCoolOffTime:                 new anchor.BN(60),         // u32
+      councilVoteThreshold: { disabled: {} },                       // 不使用理事会
+      councilVoteTipping: { strict: {} },
+      minCouncilWeightToCreateProposal: new anchor.BN(1),           // u64
+      councilVetoVoteThreshold: { disabled: {} },                   // 不允许否决
+      transactionsHoldUpTime:            0,                         // u32
+      votingBaseTime:                    120,                       // u32
+      votingCoolOffTime:                 60,                        // u32
+      depositExemptProposalCount:        0,                         // Option<u8>，测试中每个提案都收押金
+      proposalDepositAmount:             new anchor.BN(100_000_000),// Option<u64>，每份 0.1 SOL
+      proposalDepositType:               { sol: {} },
+      depositSlashTurnoutPercentage:     null,                      // 不罚没押金
+      maxDraftTime:                      86_400,                    // u32，草稿最长保留一天
+      transactionRetryTime:              3_600,                     // u32，失败交易可在一小时内重试
     } as any;
 
     // console.log(
@@ -278,9 +896,9 @@ describe("easy-dao", () => {
     //   )
     // );
   
-    // 2. 计算 Governance PDA：seeds = [realm, "governance"]
+    // 2. 计算 Governance PDA：seeds = [realm, "governance", governed_account]，此处治理社区 mint
     const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
       program.programId
     );
   
@@ -289,7 +907,10 @@ describe("easy-dao", () => {
       const tx = await program.methods.createGovernance(governanceConfig)
         .accounts({
           realm: realmPda,
-        })
+          governedAccount: mint,
+          tokenOwnerRecord: null,
+          voterWeightRecord: null,
+        } as any)
         .rpc();
       console.log("✅ create governance success", tx);
     } catch (err) {
@@ -332,21 +953,56 @@ describe("easy-dao", () => {
     console.log("✅ Governance 账户链上校验通过！");
   });
 
+  it("create native treasury", async () => {
+    const [governancePda] = PublicKey.findProgramAddressSync(
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
+      program.programId
+    );
+
+    await fundNativeTreasury(governancePda, LAMPORTS_PER_SOL / 10);
+
+    const treasuryBalance = await connection.getBalance(getNativeTreasuryPda(governancePda));
+    const rentExempt = await connection.getMinimumBalanceForRentExemption(0);
+    if (treasuryBalance !== rentExempt + LAMPORTS_PER_SOL / 10) {
+      throw new Error(`❌ 金库余额错误, expected: ${rentExempt + LAMPORTS_PER_SOL / 10}, got: ${treasuryBalance}`);
+    }
+    console.log("✅ create native treasury 校验通过！");
+  });
+
   it("create proposal", async () => {
+    const [ownerRecordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("governance"),
+        realmPda.toBuffer(),
+        mint.toBuffer(),
+        users[0].publicKey.toBuffer()
+      ],
+      program.programId
+    );
+    const [governancePda] = PublicKey.findProgramAddressSync(
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
+      program.programId
+    );
+
     const tx = await program.methods.createProposal(
       "终极测试提案667",
-      "https://example.com"
+      "https://example.com",
+      { singleChoice: {} },
+      ["通过"]
     ).accounts({
       mint: mint,
       authority: users[0].publicKey,
       realm: realmPda,
+      governance: governancePda,
+      tokenOwnerRecord: ownerRecordPda,
+      voterWeightRecord: null,
+      proposerTokenAccount: null,
+      depositTokenAccount: null,
+      tokenProgram: null,
+      associatedTokenProgram: null,
     } as any).signers([users[0]]).rpc();
     console.log("✅ create proposal success", tx);
 
-    const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
-      program.programId
-    );
     const governanceAccount = await program.account.governance.fetch(governancePda);
     console.log("governanceAccount: ", governanceAccount);
 
@@ -367,7 +1023,7 @@ describe("easy-dao", () => {
       [
         governancePda.toBuffer(),
         tokenOwnerRecordPda.toBuffer(),
-        new anchor.BN(7).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
@@ -401,10 +1057,10 @@ describe("easy-dao", () => {
       if (!proposalDepositAccountInfo) {
         throw new Error("❌ 找不到提案押金账户");
       }
-      // 计算当前网络 73 字节账户所需的最小租金
-      let minRent = new anchor.BN(await connection.getMinimumBalanceForRentExemption(73));
-      // 加上押金 0.1 sol (提案数 * 0.1)
-      minRent = minRent.add(new anchor.BN(500_000_000));
+      // 计算当前网络 83 字节账户所需的最小租金
+      let minRent = new anchor.BN(await connection.getMinimumBalanceForRentExemption(83));
+      // 加上押金：(活跃提案数 + 1 - 免押金数量) * 0.1 SOL，这是该治理下的第一个提案，免押金数量为 0
+      minRent = minRent.add(new anchor.BN(100_000_000));
       if (!minRent.eq(new anchor.BN(proposalDepositAccountInfo.lamports))) {
         throw new Error(`❌ 押金账户 lamports 错误, expected: ${minRent.toString()}, got: ${proposalDepositAccountInfo.lamports}`);
       }
@@ -413,7 +1069,7 @@ describe("easy-dao", () => {
 
   it("Adding and removing required signatories", async () => {
     const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
       program.programId
     );
   
@@ -437,6 +1093,7 @@ describe("easy-dao", () => {
         realm: realmPda,
         authority: payer,
         signatory: users[2].publicKey,  // 移除第三个
+        rentPayer: payer,
       } as any)
       .rpc();
     console.log(`✅ remove required signatory ${users[2].publicKey.toBase58()} success, tx: ${tx}`);
@@ -450,7 +1107,7 @@ describe("easy-dao", () => {
 
   it("add signatory", async () => {
     const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
       program.programId
     );
 
@@ -469,7 +1126,7 @@ describe("easy-dao", () => {
       [
         governancePda.toBuffer(),
         tokenOwnerRecordPda.toBuffer(),
-        new anchor.BN(3).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
@@ -562,7 +1219,7 @@ describe("easy-dao", () => {
 
   it("add transaction", async () => {
     const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
       program.programId
     );
 
@@ -581,29 +1238,22 @@ describe("easy-dao", () => {
       [
         governancePda.toBuffer(),
         tokenOwnerRecordPda.toBuffer(),
-        new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
 
-    // 读取指令数据
-    const DATA_PATH = path.resolve(__dirname, "init_swap_instruction.json");
-    const data = fs.readFileSync(DATA_PATH, "utf-8");
-    const json_data = JSON.parse(data);
-    const instructionData = {
-      programId: new PublicKey(json_data.program_id),
-      data: Buffer.from(json_data.data, "base64"),
-      accounts: json_data.accounts.map((account: any) => ({
-        pubkey: new PublicKey(account.pubkey),
-        isSigner: account.is_signer,
-        isWritable: account.is_writable
-      }))
-    };
+    // 提案指令：从治理原生金库向 users[19] 转账，金库由程序代签
+    const instructionData = buildTreasuryTransferInstruction(governancePda, users[19].publicKey);
 
     const tx = await program.methods.addTransaction(
-      instructionData
+      0,
+      0,
+      0,
+      [instructionData]
     )
     .accounts({
+      governance: governancePda,
       proposal: proposalPda,
       authority: users[0].publicKey,
       tokenOwnerRecord: tokenOwnerRecordPda,
@@ -613,15 +1263,17 @@ describe("easy-dao", () => {
 
     // 校验
     const proposalAccount = await program.account.proposal.fetch(proposalPda);
-    if (!proposalAccount.hasTransaction) {
-      throw new Error("❌ hasTransaction 应为 true");
+    if (proposalAccount.options[0].transactionsCount !== 1) {
+      throw new Error("❌ transactionsCount 应为 1");
     }
-    console.log("✅ hasTransaction 校验通过！");
+    console.log("✅ transactionsCount 校验通过！");
 
     const [proposalTransactionPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("proposal_transaction"),
-        proposalPda.toBuffer()
+        proposalPda.toBuffer(),
+        Buffer.from([0]),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 2)
       ],
       program.programId
     );
@@ -629,7 +1281,7 @@ describe("easy-dao", () => {
       .proposalTransaction
       .fetch(proposalTransactionPda);
 
-    if (!proposalTransactionAccount.instruction.programId.equals(instructionData.programId)) {
+    if (!proposalTransactionAccount.instructions[0].programId.equals(instructionData.programId)) {
       throw new Error("❌ instruction.programId 不匹配");
     }
     console.log("✅ instruction.programId 校验通过！");
@@ -639,7 +1291,7 @@ describe("easy-dao", () => {
 
   it("sign off proposal", async () => { 
     const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
       program.programId
     );
 
@@ -658,7 +1310,7 @@ describe("easy-dao", () => {
       [
         governancePda.toBuffer(),
         tokenOwnerRecordPda.toBuffer(),
-        new anchor.BN(3).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
@@ -676,7 +1328,7 @@ describe("easy-dao", () => {
     // .rpc();
     // console.log(`✅ sign off proposal success, tx: ${tx}`);
 
-    for (let i = 1; i < 2; i++) {
+    for (let i = 0; i < 3; i++) {
       const [signatoryRecordPda] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("signatory_record"),
@@ -700,40 +1352,40 @@ describe("easy-dao", () => {
       console.log(`✅ sign off proposal success, tx: ${tx}`);
     }
 
-    // for (let i = 0; i < 3; i++) {
-    //   const [signatoryRecordPda] = PublicKey.findProgramAddressSync(
-    //     [
-    //       Buffer.from("signatory_record"),
-    //       proposalPda.toBuffer(),
-    //       users[i].publicKey.toBuffer()
-    //     ],
-    //     program.programId
-    //   );
-    //   const signatoryRecordAccount = await program.account
-    //     .signatoryRecord
-    //     .fetch(signatoryRecordPda);
-
-    //   if (!signatoryRecordAccount.signedOff) {
-    //     throw new Error("❌ signatoryRecordAccount.signedOff 应为 true");
-    //   }
-    //   console.log("✅ signatoryRecordAccount 校验通过！");
-    // }
+    for (let i = 0; i < 3; i++) {
+      const [signatoryRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("signatory_record"),
+          proposalPda.toBuffer(),
+          users[i].publicKey.toBuffer()
+        ],
+        program.programId
+      );
+      const signatoryRecordAccount = await program.account
+        .signatoryRecord
+        .fetch(signatoryRecordPda);
+
+      if (!signatoryRecordAccount.signedOff) {
+        throw new Error("❌ signatoryRecordAccount.signedOff 应为 true");
+      }
+      console.log("✅ signatoryRecordAccount 校验通过！");
+    }
 
-    // const proposalAccount = await program.account.proposal.fetch(proposalPda);
+    const proposalAccount = await program.account.proposal.fetch(proposalPda);
     
-    // if (!proposalAccount.state.voting) {
-    //   throw new Error("❌ state 应为 Voting");
-    // }
-    // if (proposalAccount.signatoriesSignedOffCount !== proposalAccount.signatoriesCount) {
-    //   throw new Error("❌ signatoriesSignedOffCount 应为 signatoriesCount");
-    // }
+    if (!("voting" in proposalAccount.state)) {
+      throw new Error("❌ state 应为 Voting");
+    }
+    if (proposalAccount.signatoriesSignedOffCount !== proposalAccount.signatoriesCount) {
+      throw new Error("❌ signatoriesSignedOffCount 应为 signatoriesCount");
+    }
     console.log("✅ proposalAccount 校验通过！");
 
   })
 
   it("cast vote", async () => {
     const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
       program.programId
     );
 
@@ -758,13 +1410,23 @@ describe("easy-dao", () => {
       [
         governancePda.toBuffer(),
         tokenOwnerRecordPda.toBuffer(),
-        new anchor.BN(3).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
 
-    for (let i = 13; i < 18; i++) {
+    // 每人存入 20 枚，最大票权 2000 枚，门槛 10% 即 200 枚：第 13 张票时赞成票达到 200 枚，提前通过
+    for (let i = 0; i < 13; i++) {
       const voteArg: any = (i >= 5 && i <= 7) ? { no: {} } : { yes: {} };
+      const [voteTokenOwnerRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("governance"),
+          realmPda.toBuffer(),
+          mint.toBuffer(),
+          users[i].publicKey.toBuffer()
+        ],
+        program.programId
+      );
       const tx = await program.methods.castVote(
         voteArg
       )
@@ -775,6 +1437,10 @@ describe("easy-dao", () => {
         mint: mint,
         authority: users[i].publicKey,
         user: users[0].publicKey,
+        voteTokenOwnerRecord: voteTokenOwnerRecordPda,
+        tokenOwnerRecord: tokenOwnerRecordPda,
+        voterWeightRecord: null,
+        maxVoterWeightRecord: null,
       } as any)
       .signers([users[i]])
       .rpc();
@@ -784,73 +1450,64 @@ describe("easy-dao", () => {
 
     const proposalAccount = await program.account.proposal.fetch(proposalPda);
     console.log("proposalAccount: ", proposalAccount);
-    console.log("proposalAccount: ", proposalAccount.yesVoteWeight.toString());
+    console.log("proposalAccount: ", proposalAccount.options[0].voteWeight.toString());
     console.log("proposalAccount: ", proposalAccount.noVoteWeight.toString());
-    // if (!proposalAccount.state.executing) {
-    //   throw new Error("❌ state 应为 Executing");
-    // }
-    // if (proposalAccount.yesVoteWeight !== new anchor.BN(12)) {
-    //   throw new Error("❌ yesVoteWeight 应为 12");
-    // }
-    // if (proposalAccount.noVoteWeight !== new anchor.BN(3)) {
-    //   throw new Error("❌ noVoteWeight 应为 3");
-    // }
+    if (!("executing" in proposalAccount.state)) {
+      throw new Error("❌ state 应为 Executing");
+    }
+    if (!proposalAccount.options[0].voteWeight.eq(new anchor.BN(200 * ONE_TOKEN))) {
+      throw new Error("❌ yesVoteWeight 应为 200");
+    }
+    if (!proposalAccount.noVoteWeight.eq(new anchor.BN(60 * ONE_TOKEN))) {
+      throw new Error("❌ noVoteWeight 应为 60");
+    }
     const tokenOwnerRecordAccount3 = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
     console.log("tokenOwnerRecordAccount3: ", tokenOwnerRecordAccount3);
     console.log("✅ cast vote 校验通过！");
   })
 
   it("finalize vote", async () => {
-    const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
-      program.programId
-    );
+    // 上面的提案已提前通过，这里使用不提前结束、投票期较短的治理账户
+    const governancePda = await createGovernance(buildGovernanceConfig({
+      communityVoteTipping: { disabled: {} },
+      votingBaseTime: 20,
+      votingCoolOffTime: 0,
+    }));
+    const proposalPda = await createProposal(users[3], governancePda);
+    await signOffProposal(users[3], governancePda, proposalPda);
 
-    const governanceAccount = await program.account.governance.fetch(governancePda);
-    console.log("governanceAccount-: ", governanceAccount);
+    for (let i = 3; i < 13; i++) {
+      await castVote(users[i], governancePda, proposalPda, { yes: {} });
+    }
 
-    const [tokenOwnerRecordPda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("governance"), 
-        realmPda.toBuffer(), 
-        mint.toBuffer(), 
-        users[0].publicKey.toBuffer()
-      ],
-      program.programId
-    );
+    // 赞成票已达到门槛，但 Disabled 模式下必须等投票期结束
+    let proposalAccount = await program.account.proposal.fetch(proposalPda);
+    if (!("voting" in proposalAccount.state)) {
+      throw new Error("❌ 投票期结束前 state 应为 Voting");
+    }
+    await expectAnchorError(finalizeVote(governancePda, proposalPda), "ProposalStillInVoting");
 
-    // 计算提案账户
-    const [proposalPda] = PublicKey.findProgramAddressSync(
-      [
-        governancePda.toBuffer(),
-        tokenOwnerRecordPda.toBuffer(),
-        new anchor.BN(2).toArrayLike(Buffer, "le", 8),
-      ],
-      program.programId
-    );
+    await waitForVotingEnd(governancePda, proposalPda);
+    await finalizeVote(governancePda, proposalPda);
 
-    const tx = await program.methods.finalizeVote()
-    .accounts({
-      proposal: proposalPda,
-      governance: governancePda,
-      realm: realmPda,
-      mint: mint,
-      user: users[0].publicKey,
-    } as any)
-    .rpc();
-    console.log(`✅ finalize vote success, tx: ${tx}`);
+    proposalAccount = await program.account.proposal.fetch(proposalPda);
+    console.log("proposalAccount: ", proposalAccount.state);
+    if (!("completed" in proposalAccount.state)) {
+      throw new Error("❌ 没有交易的提案通过后 state 应为 Completed");
+    }
+    if (!("succeeded" in proposalAccount.options[0].voteResult)) {
+      throw new Error("❌ 选项结果应为 Succeeded");
+    }
+    if (!proposalAccount.closedAt) {
+      throw new Error("❌ closedAt 应已设置");
+    }
 
-    const proposalAccount = await program.account.proposal.fetch(proposalPda);
-    console.log("proposalAccount: ", proposalAccount);
-    console.log("proposalAccount: ", proposalAccount.votingStartedAt);
-    
-    
     console.log("✅ finalize vote 校验通过！");
   })
 
   it("execute transaction", async () => {
     const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
       program.programId
     );
 
@@ -869,7 +1526,7 @@ describe("easy-dao", () => {
       [
         governancePda.toBuffer(),
         tokenOwnerRecordPda.toBuffer(),
-        new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
@@ -879,21 +1536,13 @@ describe("easy-dao", () => {
       [
         Buffer.from("proposal_transaction"),
         proposalPda.toBuffer(),
+        Buffer.from([0]),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 2)
       ],
       program.programId
     );
 
-    // 获取提案指令数据
-    const DATA_PATH = path.resolve(__dirname, "init_swap_instruction.json");
-    const data = fs.readFileSync(DATA_PATH, "utf-8");
-    const json_data = JSON.parse(data);
-
-    // 组装 remainingAccounts 数组
-    const remainingAccounts = json_data.accounts.map((acc: any) => ({
-      pubkey: new PublicKey(acc.pubkey),
-      isSigner: acc.is_signer,
-      isWritable: acc.is_writable,
-    }));
+    const recipientBalance = await connection.getBalance(users[19].publicKey);
 
     const tx = await program.methods.executeTransaction()
       .accounts({
@@ -901,8 +1550,7 @@ describe("easy-dao", () => {
         governance: governancePda,
         proposalTransaction: proposalTransactionPda,
       } as any)
-      .remainingAccounts(remainingAccounts)
-      .signers([users[20]])
+      .remainingAccounts(getRemainingAccounts(buildTreasuryTransferInstruction(governancePda, users[19].publicKey)))
       .rpc();
 
     console.log(`✅ execute transaction success, tx: ${tx}`);
@@ -936,14 +1584,26 @@ describe("easy-dao", () => {
 
     console.log("proposalTransactionAccount: ", 
       proposalTransactionAccount.executionStatus);
+    if (!("success" in proposalTransactionAccount.executionStatus)) {
+      throw new Error("❌ executionStatus 应为 Success");
+    }
 
     const proposalAccount = await program.account.proposal.fetch(proposalPda);
     console.log("proposalAccount: ", proposalAccount.state);
+    if (!("completed" in proposalAccount.state)) {
+      throw new Error("❌ 全部交易执行后 state 应为 Completed");
+    }
+
+    const recipientBalance2 = await connection.getBalance(users[19].publicKey);
+    if (recipientBalance2 !== recipientBalance + TREASURY_TRANSFER_LAMPORTS) {
+      throw new Error(`❌ 金库转账金额错误, expected: ${recipientBalance + TREASURY_TRANSFER_LAMPORTS}, got: ${recipientBalance2}`);
+    }
+    console.log("✅ execute transaction 校验通过！");
   })
 
   it("relinquish vote", async () => {
     const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
       program.programId
     );
 
@@ -961,7 +1621,7 @@ describe("easy-dao", () => {
       [
         governancePda.toBuffer(),
         tokenOwnerRecordPda.toBuffer(),
-        new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
@@ -994,6 +1654,7 @@ describe("easy-dao", () => {
           signer: users[i].publicKey,
           tokenOwnerRecord: tokenOwnerRecordPda2,
           voteRecord: voteRecordPda,
+          rentPayer: users[i].publicKey,
         } as any)
         .signers([users[i]])
         .rpc();
@@ -1015,9 +1676,60 @@ describe("easy-dao", () => {
     console.log("✅ relinquish vote 校验通过！");
   })
 
+  it("close vote record", async () => {
+    const [tokenOwnerRecordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("governance"), 
+        realmPda.toBuffer(), 
+        mint.toBuffer(), 
+        users[0].publicKey.toBuffer()
+      ],
+      program.programId
+    );
+
+    const [governancePda] = PublicKey.findProgramAddressSync(
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
+      program.programId
+    );
+
+    const [proposalPda] = PublicKey.findProgramAddressSync(
+      [
+        governancePda.toBuffer(),
+        tokenOwnerRecordPda.toBuffer(),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    const [voteRecordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vote_record"),
+        proposalPda.toBuffer(),
+        tokenOwnerRecordPda.toBuffer()
+      ],
+      program.programId
+    );
+
+    const tx = await program.methods.closeVoteRecord()
+      .accounts({
+        authority: users[0].publicKey,
+        tokenOwnerRecord: tokenOwnerRecordPda,
+        voteRecord: voteRecordPda,
+        rentPayer: users[0].publicKey,
+      } as any)
+      .signers([users[0]])
+      .rpc();
+    console.log(`✅ close vote record success, tx: ${tx}`);
+
+    const voteRecordInfo = await connection.getAccountInfo(voteRecordPda);
+    if (voteRecordInfo !== null) {
+      throw new Error("❌ voteRecord 应已关闭");
+    }
+  })
+
   it("refund proposal deposit", async () => {
     const [governancePda] = PublicKey.findProgramAddressSync(
-      [realmPda.toBuffer(), Buffer.from("governance")],
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
       program.programId
     );
 
@@ -1040,7 +1752,7 @@ describe("easy-dao", () => {
       [
         governancePda.toBuffer(),
         tokenOwnerRecordPda.toBuffer(),
-        new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
@@ -1060,9 +1772,15 @@ describe("easy-dao", () => {
       proposalDeposit: proposalDepositPda,
       realm: realmPda,
       mint: mint,
-      authority: users[0].publicKey,
+      governance: governancePda,
+      tokenOwnerRecord: tokenOwnerRecordPda,
+      depositPayer: users[0].publicKey,
+      nativeTreasury: null,
+      depositTokenAccount: null,
+      depositPayerTokenAccount: null,
+      treasuryTokenAccount: null,
+      tokenProgram: null,
     } as any)
-    .signers([users[0]])
     .rpc();
     console.log(`✅ refund proposal deposit success, tx: ${tx}`);
 
@@ -1079,13 +1797,1385 @@ describe("easy-dao", () => {
     console.log("✅ refund proposal deposit 校验通过！");
   })
 
-  it.only("hhh", async () => {
-    console.log(users[0].publicKey.toBase58());
-    console.log(users[1].publicKey.toBase58());
-    console.log(users[2].publicKey.toBase58());
-    console.log(users[3].publicKey.toBase58());
-    console.log(users[4].publicKey.toBase58());
-    console.log(users[5].publicKey.toBase58());
+  it("cancel proposal", async () => {
+    const user = users[1];
+    const [governancePda] = PublicKey.findProgramAddressSync(
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
+      program.programId
+    );
+
+    const [tokenOwnerRecordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("governance"),
+        realmPda.toBuffer(),
+        mint.toBuffer(),
+        user.publicKey.toBuffer()
+      ],
+      program.programId
+    );
+    const tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+
+    const [proposalPda] = PublicKey.findProgramAddressSync(
+      [
+        governancePda.toBuffer(),
+        tokenOwnerRecordPda.toBuffer(),
+        tokenOwnerRecordAccount.proposalIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods.createProposal(
+      "待取消提案",
+      "https://example.com",
+      { singleChoice: {} },
+      ["通过"]
+    ).accounts({
+      mint: mint,
+      authority: user.publicKey,
+      realm: realmPda,
+      governance: governancePda,
+      tokenOwnerRecord: tokenOwnerRecordPda,
+      voterWeightRecord: null,
+      proposerTokenAccount: null,
+      depositTokenAccount: null,
+      tokenProgram: null,
+      associatedTokenProgram: null,
+    } as any).signers([user]).rpc();
+
+    const tx = await program.methods.cancelProposal()
+      .accounts({
+        authority: user.publicKey,
+        realm: realmPda,
+        governance: governancePda,
+        tokenOwnerRecord: tokenOwnerRecordPda,
+        proposal: proposalPda,
+      } as any)
+      .signers([user])
+      .rpc();
+    console.log(`✅ cancel proposal success, tx: ${tx}`);
+
+    const proposalAccount = await program.account.proposal.fetch(proposalPda);
+    if (!("cancelled" in proposalAccount.state)) {
+      throw new Error("❌ state 应为 Cancelled");
+    }
+    if (!proposalAccount.closedAt) {
+      throw new Error("❌ closedAt 应已设置");
+    }
+    console.log("✅ cancel proposal 校验通过！");
+  })
+
+  it("create multi choice proposal", async () => {
+    const user = users[2];
+    const [governancePda] = PublicKey.findProgramAddressSync(
+      [realmPda.toBuffer(), Buffer.from("governance"), mint.toBuffer()],
+      program.programId
+    );
+
+    const [tokenOwnerRecordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("governance"),
+        realmPda.toBuffer(),
+        mint.toBuffer(),
+        user.publicKey.toBuffer()
+      ],
+      program.programId
+    );
+    const tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+
+    const [proposalPda] = PublicKey.findProgramAddressSync(
+      [
+        governancePda.toBuffer(),
+        tokenOwnerRecordPda.toBuffer(),
+        tokenOwnerRecordAccount.proposalIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods.createProposal(
+      "预算分配",
+      "https://example.com",
+      { multiChoice: { maxVoterOptions: 2, maxWinningOptions: 1 } },
+      ["方案A", "方案B", "方案C"]
+    ).accounts({
+      mint: mint,
+      authority: user.publicKey,
+      realm: realmPda,
+      governance: governancePda,
+      tokenOwnerRecord: tokenOwnerRecordPda,
+      voterWeightRecord: null,
+      proposerTokenAccount: null,
+      depositTokenAccount: null,
+      tokenProgram: null,
+      associatedTokenProgram: null,
+    } as any).signers([user]).rpc();
+
+    const proposalAccount = await program.account.proposal.fetch(proposalPda);
+    if (proposalAccount.options.length !== 3) {
+      throw new Error("❌ options 数量应为 3");
+    }
+    if (proposalAccount.options[1].label !== "方案B") {
+      throw new Error("❌ options[1].label 不匹配");
+    }
+    console.log("✅ create multi choice proposal 校验通过！");
   })
 
+  it("multi choice proposal has no cool off time", async () => {
+    // 冷却期只能投反对或否决票，多选提案没有反对票，因此投票基础时长结束即结束投票
+    const governance = await createGovernance(buildGovernanceConfig({
+      votingBaseTime: 5,
+      votingCoolOffTime: 3_600,
+    }));
+    const proposal = await createProposal(users[16], governance, {
+      voteType: { multiChoice: { maxVoterOptions: 1, maxWinningOptions: 1 } },
+      labels: ["方案A", "方案B"],
+    });
+    await signOffProposal(users[16], governance, proposal);
+    await castVote(users[0], governance, proposal, approveVote(2, [0]));
+
+    const votingStartedAt = (await program.account.proposal.fetch(proposal)).votingStartedAt.toNumber();
+    await waitUntilChainTime(votingStartedAt + 5);
+    await expectAnchorError(
+      castVote(users[1], governance, proposal, approveVote(2, [1])),
+      "ProposalVotingTimeExpired"
+    );
+
+    // 不必等待冷却期即可结束投票，20 枚未达到 10% 门槛
+    await finalizeVote(governance, proposal);
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("defeated" in proposalAccount.state)) {
+      throw new Error(`❌ 投票基础时长结束后应可结束投票, got: ${JSON.stringify(proposalAccount.state)}`);
+    }
+    console.log("✅ multi choice proposal cool off 校验通过！");
+  });
+
+  it("multi choice approve votes, ranking and execution", async () => {
+    // 门槛 10% 即 200 枚；每人最多选 2 项，只有得票最高的 1 项通过
+    const governance = await createGovernance(buildGovernanceConfig({ votingBaseTime: 30 }));
+    await fundNativeTreasury(governance, LAMPORTS_PER_SOL / 10);
+    const proposer = users[16];
+    const proposal = await createProposal(proposer, governance, {
+      voteType: { multiChoice: { maxVoterOptions: 2, maxWinningOptions: 1 } },
+      labels: ["方案A", "方案B", "方案C"],
+    });
+    const optionAInstruction = buildTreasuryTransferInstruction(governance, users[16].publicKey);
+    const optionBInstruction = buildTreasuryTransferInstruction(governance, users[17].publicKey);
+    await addTransaction(proposer, governance, proposal, optionAInstruction, 0, 0, 0);
+    await addTransaction(proposer, governance, proposal, optionBInstruction, 0, 0, 1);
+    await signOffProposal(proposer, governance, proposal);
+
+    // 多选提案不接受赞成/反对票，选择数量不能超过 maxVoterOptions
+    await expectAnchorError(castVote(users[0], governance, proposal, { yes: {} }), "InvalidVote");
+    await expectAnchorError(castVote(users[0], governance, proposal, approveVote(3, [0, 1, 2])), "InvalidVote");
+
+    // 12 人选 A、B，2 人只选 B：A 240 枚，B 280 枚，均达到门槛
+    for (let i = 0; i < 12; i++) {
+      await castVote(users[i], governance, proposal, approveVote(3, [0, 1]));
+    }
+    for (let i = 12; i < 14; i++) {
+      await castVote(users[i], governance, proposal, approveVote(3, [1]));
+    }
+
+    // 认可投票的票权全额计入每个选中的选项，撤回时从每个选项中扣除
+    const expectOptionWeights = async (expected: number[], message: string) => {
+      const proposalAccount = await program.account.proposal.fetch(proposal);
+      const actual = proposalAccount.options.map(option => option.voteWeight.toNumber() / ONE_TOKEN);
+      if (actual.some((weight, i) => weight !== expected[i])) {
+        throw new Error(`❌ ${message}, got: ${actual.join(", ")}`);
+      }
+      return proposalAccount;
+    };
+    await castVote(users[14], governance, proposal, approveVote(3, [1, 2]));
+    const votingAccount = await expectOptionWeights([240, 300, 20], "认可投票应计入每个选中的选项");
+    if (!votingAccount.castVoteWeight.eq(new anchor.BN(15 * 20 * ONE_TOKEN))) {
+      throw new Error("❌ castVoteWeight 每票只应计入一次");
+    }
+    await relinquishVote(users[14], governance, proposal);
+    await expectOptionWeights([240, 280, 0], "撤回认可投票后应从各选项中扣除");
+
+    // 两个选项都达到门槛，按得票排序后只保留最高的 1 个
+    await waitForVotingEnd(governance, proposal);
+    await finalizeVote(governance, proposal);
+    let proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("executing" in proposalAccount.state)) {
+      throw new Error(`❌ 通过的选项有交易，提案应进入 Executing, got: ${JSON.stringify(proposalAccount.state)}`);
+    }
+    const voteResults = proposalAccount.options.map(option => Object.keys(option.voteResult)[0]);
+    if (voteResults.join(",") !== "defeated,succeeded,defeated") {
+      throw new Error(`❌ 只有得票最高的方案B应通过, got: ${voteResults.join(",")}`);
+    }
+
+    // 只能执行通过选项的交易，执行完毕后提案完成
+    await expectAnchorError(
+      executeTransaction(governance, proposal, optionAInstruction, 0, 0),
+      "CannotExecuteDefeatedOption"
+    );
+    const recipientBalance = await connection.getBalance(users[17].publicKey);
+    await executeTransaction(governance, proposal, optionBInstruction, 0, 1);
+    if (await connection.getBalance(users[17].publicKey) !== recipientBalance + TREASURY_TRANSFER_LAMPORTS) {
+      throw new Error("❌ 方案B的交易应已执行");
+    }
+    proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("completed" in proposalAccount.state)) {
+      throw new Error("❌ 通过选项的交易全部执行后提案应完成");
+    }
+    console.log("✅ multi choice approve votes 校验通过！");
+  });
+
+  it("create realm rejects invalid council accounts", async () => {
+    const realmConfig = {
+      minCommunityWeightToCreateGovernance: new anchor.BN(1),
+      communityMintMaxVoterWeightSource: { supplyFraction: [new anchor.BN(10_000_000)] },
+    } as any;
+    const governingTokenConfig = {
+      tokenType: { liquid: {} },
+      lockAuthorities: [],
+      voterWeightAddin: null,
+      maxVoterWeightAddin: null,
+    } as any;
+    const realm = getRealmPda(councilRealmId);
+
+    // 理事会代币与社区代币相同
+    await expectAnchorError(
+      program.methods.createRealm(councilRealmId, "理事会", realmConfig, governingTokenConfig, governingTokenConfig)
+        .accounts({
+          authority: payer,
+          mint: mint,
+          councilMint: mint,
+          councilTokenAccount: getCouncilTokenPda(mint, realm),
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID
+        } as any)
+        .rpc(),
+      "InvalidCouncilMint"
+    );
+
+    // 只提供理事会代币 mint，没有托管账户
+    const otherMint = await createMint(connection, users[0], users[0].publicKey, null, 0);
+    await expectAnchorError(
+      program.methods.createRealm(councilRealmId, "理事会", realmConfig, governingTokenConfig, governingTokenConfig)
+        .accounts({
+          authority: payer,
+          mint: mint,
+          councilMint: otherMint,
+          councilTokenAccount: null,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID
+        } as any)
+        .rpc(),
+      "InvalidCouncilTokenAccount"
+    );
+
+    // 名称超过 32 字节（11 个汉字 = 33 字节）
+    await expectAnchorError(
+      program.methods.createRealm(councilRealmId, "理事会名称长度超过上限", realmConfig, governingTokenConfig, governingTokenConfig)
+        .accounts({
+          authority: payer,
+          mint: mint,
+          councilMint: null,
+          councilTokenAccount: null,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID
+        } as any)
+        .rpc(),
+      "NameTooLong"
+    );
+    console.log("✅ create realm 理事会账户校验通过！");
+  });
+
+  it("create council realm", async () => {
+    const realmConfig = {
+      minCommunityWeightToCreateGovernance: new anchor.BN(1),
+      communityMintMaxVoterWeightSource: { supplyFraction: [new anchor.BN(10_000_000)] },
+    } as any;
+    const governingTokenConfig = {
+      tokenType: { liquid: {} },
+      lockAuthorities: [],
+      voterWeightAddin: null,
+      maxVoterWeightAddin: null,
+    } as any;
+
+    councilRealmPda = getRealmPda(councilRealmId);
+    // 理事会代币无小数位，users[0..2] 各持有 1 枚，最大票权为 3
+    councilMint = await createMint(connection, users[0], users[0].publicKey, null, 0);
+    for (let i = 0; i < 3; i++) {
+      const ata = await getOrCreateAssociatedTokenAccount(connection, users[0], councilMint, users[i].publicKey);
+      await mintTo(connection, users[0], councilMint, ata.address, users[0], 1);
+    }
+
+    await program.methods.createRealm(councilRealmId, "理事会", realmConfig, governingTokenConfig, governingTokenConfig)
+      .accounts({
+        authority: payer,
+        mint: mint,
+        councilMint,
+        councilTokenAccount: getCouncilTokenPda(councilMint, councilRealmPda),
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID
+      } as any)
+      .rpc();
+
+    const realmAccount = await program.account.realm.fetch(councilRealmPda);
+    if (!realmAccount.councilMint?.equals(councilMint)) {
+      throw new Error("❌ councilMint 不匹配");
+    }
+    if (!realmAccount.councilTokenAccount?.equals(getCouncilTokenPda(councilMint, councilRealmPda))) {
+      throw new Error("❌ councilTokenAccount 不匹配");
+    }
+
+    // 理事会成员存入理事会代币，社区成员存入社区代币
+    for (let i = 0; i < 3; i++) {
+      await createTokenOwnerRecordAndDeposit(
+        users[i], councilMint, councilRealmPda, getCouncilTokenPda(councilMint, councilRealmPda), 1
+      );
+    }
+    for (let i = 3; i < 15; i++) {
+      await createTokenOwnerRecordAndDeposit(
+        users[i], mint, councilRealmPda, getCommunityTokenPda(mint, councilRealmPda), 20 * ONE_TOKEN
+      );
+    }
+
+    const councilRecord = await program.account.tokenOwnerRecord.fetch(
+      getTokenOwnerRecordPda(users[0].publicKey, councilMint, councilRealmPda)
+    );
+    if (!councilRecord.governingTokenDepositAmount.eq(new anchor.BN(1))) {
+      throw new Error("❌ 理事会代币存款应为 1");
+    }
+    console.log("✅ create council realm 校验通过！");
+  });
+
+  it("set and relinquish token owner record lock", async () => {
+    // payer 是 lockRealm 社区代币的锁定权限人
+    lockRealmPda = await createCommunityRealm(lockRealmId, [payer]);
+    const user = users[15];
+    const holding = getCommunityTokenPda(mint, lockRealmPda);
+    await createTokenOwnerRecordAndDeposit(user, mint, lockRealmPda, holding, 10 * ONE_TOKEN);
+    const tokenOwnerRecordPda = getTokenOwnerRecordPda(user.publicKey, mint, lockRealmPda);
+
+    const withdraw = () => program.methods.withdrawGoverningTokens(new anchor.BN(5 * ONE_TOKEN))
+      .accounts({
+        user: user.publicKey,
+        mint: mint,
+        realm: lockRealmPda,
+        governingTokenHolding: holding,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+      } as any)
+      .signers([user])
+      .rpc();
+
+    const expiry = new anchor.BN((await getChainTime()) + 3_600);
+
+    // 不在 lock_authorities 中的账户不能加锁
+    await expectAnchorError(
+      program.methods.setTokenOwnerRecordLock(1, expiry)
+        .accounts({
+          lockAuthority: users[16].publicKey,
+          realm: lockRealmPda,
+          tokenOwnerRecord: tokenOwnerRecordPda,
+        } as any)
+        .signers([users[16]])
+        .rpc(),
+      "InvalidLockAuthority"
+    );
+
+    await program.methods.setTokenOwnerRecordLock(1, expiry)
+      .accounts({
+        lockAuthority: payer,
+        realm: lockRealmPda,
+        tokenOwnerRecord: tokenOwnerRecordPda,
+      } as any)
+      .rpc();
+
+    let tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+    if (tokenOwnerRecordAccount.locks.length !== 1 || tokenOwnerRecordAccount.locks[0].lockId !== 1) {
+      throw new Error("❌ 应有一个 lockId 为 1 的锁");
+    }
+
+    // 锁未过期时不能取出
+    await expectAnchorError(withdraw(), "TokenOwnerRecordLocked");
+
+    // 只有设置锁的权限人可以解除
+    await expectAnchorError(
+      program.methods.relinquishTokenOwnerRecordLock(1)
+        .accounts({
+          lockAuthority: users[16].publicKey,
+          tokenOwnerRecord: tokenOwnerRecordPda,
+        } as any)
+        .signers([users[16]])
+        .rpc(),
+      "TokenOwnerRecordLockNotFound"
+    );
+
+    await program.methods.relinquishTokenOwnerRecordLock(1)
+      .accounts({
+        lockAuthority: payer,
+        tokenOwnerRecord: tokenOwnerRecordPda,
+      } as any)
+      .rpc();
+
+    tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+    if (tokenOwnerRecordAccount.locks.length !== 0) {
+      throw new Error("❌ 锁应已解除");
+    }
+
+    await withdraw();
+    tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+    if (!tokenOwnerRecordAccount.governingTokenDepositAmount.eq(new anchor.BN(5 * ONE_TOKEN))) {
+      throw new Error("❌ 解锁后取出，governingTokenDepositAmount 应为 5");
+    }
+    console.log("✅ token owner record lock 校验通过！");
+  });
+
+  it("set realm authority", async () => {
+    const realm = await createCommunityRealm(new anchor.BN(4));
+    const governance = await createGovernance(buildGovernanceConfig(), realm);
+    const otherRealmGovernance = await createGovernance(buildGovernanceConfig());
+
+    const setRealmAuthority = (action: any, newRealmAuthority: PublicKey | null, signer?: Keypair) => {
+      const builder = program.methods.setRealmAuthority(action)
+        .accounts({
+          authority: signer?.publicKey ?? payer,
+          realm,
+          newRealmAuthority,
+        } as any);
+      return signer ? builder.signers([signer]).rpc() : builder.rpc();
+    };
+
+    // SetChecked 只接受本 Realm 下的 Governance 账户
+    await expectAnchorError(
+      setRealmAuthority({ setChecked: {} }, users[0].publicKey),
+      "InvalidNewRealmAuthority"
+    );
+    await expectAnchorError(
+      setRealmAuthority({ setChecked: {} }, otherRealmGovernance),
+      "InvalidNewRealmAuthority"
+    );
+    await expectAnchorError(
+      setRealmAuthority({ setUnchecked: {} }, null),
+      "NewRealmAuthorityRequired"
+    );
+
+    await setRealmAuthority({ setChecked: {} }, governance);
+    let realmAccount = await program.account.realm.fetch(realm);
+    if (!realmAccount.authority?.equals(governance)) {
+      throw new Error("❌ Realm 治理权应已移交给 Governance");
+    }
+
+    // 移交后原拥有者不能再修改
+    await expectAnchorError(
+      setRealmAuthority({ setUnchecked: {} }, payer),
+      "UnauthorizedRealmAuthority"
+    );
+
+    // SetUnchecked 可交给任意账户，新拥有者可以移除治理权
+    const uncheckedRealm = await createCommunityRealm(new anchor.BN(5));
+    await program.methods.setRealmAuthority({ setUnchecked: {} })
+      .accounts({
+        authority: payer,
+        realm: uncheckedRealm,
+        newRealmAuthority: users[0].publicKey,
+      } as any)
+      .rpc();
+    realmAccount = await program.account.realm.fetch(uncheckedRealm);
+    if (!realmAccount.authority?.equals(users[0].publicKey)) {
+      throw new Error("❌ Realm 治理权应已交给 users[0]");
+    }
+
+    await program.methods.setRealmAuthority({ remove: {} })
+      .accounts({
+        authority: users[0].publicKey,
+        realm: uncheckedRealm,
+        newRealmAuthority: null,
+      } as any)
+      .signers([users[0]])
+      .rpc();
+    realmAccount = await program.account.realm.fetch(uncheckedRealm);
+    if (realmAccount.authority !== null) {
+      throw new Error("❌ Realm 治理权应已移除");
+    }
+    console.log("✅ set realm authority 校验通过！");
+  });
+
+  it("set realm config", async () => {
+    const user = users[15];
+    const tokenOwnerRecordPda = getTokenOwnerRecordPda(user.publicKey, mint, lockRealmPda);
+    const communityTokenConfig = {
+      tokenType: { liquid: {} },
+      lockAuthorities: [payer],
+      voterWeightAddin: null,
+      maxVoterWeightAddin: null,
+    } as any;
+    const councilTokenConfig = { ...communityTokenConfig, lockAuthorities: [] } as any;
+    const buildRealmConfig = (minCommunityWeightToCreateGovernance: number, maxVoterWeightSource: any) => ({
+      minCommunityWeightToCreateGovernance: new anchor.BN(minCommunityWeightToCreateGovernance),
+      communityMintMaxVoterWeightSource: maxVoterWeightSource,
+    } as any);
+    const supplyFraction = { supplyFraction: [new anchor.BN(10_000_000)] };
+
+    const setRealmConfig = (realmConfig: any, tokenConfig: any = communityTokenConfig) =>
+      program.methods.setRealmConfig(realmConfig, tokenConfig, councilTokenConfig)
+        .accounts({
+          authority: payer,
+          realm: lockRealmPda,
+        } as any)
+        .rpc();
+
+    // 非 Realm 管理员不能修改配置
+    await expectAnchorError(
+      program.methods.setRealmConfig(buildRealmConfig(1, supplyFraction), communityTokenConfig, councilTokenConfig)
+        .accounts({
+          authority: user.publicKey,
+          realm: lockRealmPda,
+        } as any)
+        .signers([user])
+        .rpc(),
+      "UnauthorizedRealmAuthority"
+    );
+    await expectAnchorError(
+      setRealmConfig(buildRealmConfig(1, { absolute: [new anchor.BN(0)] })),
+      "InvalidMintMaxVoterWeightSource"
+    );
+    await expectAnchorError(
+      setRealmConfig(
+        buildRealmConfig(1, supplyFraction),
+        { ...communityTokenConfig, lockAuthorities: Array.from({ length: 6 }, () => Keypair.generate().publicKey) }
+      ),
+      "TooManyLockAuthorities"
+    );
+
+    const createGovernanceByUser = () => {
+      const governedAccount = Keypair.generate().publicKey;
+      const [governancePda] = PublicKey.findProgramAddressSync(
+        [lockRealmPda.toBuffer(), Buffer.from("governance"), governedAccount.toBuffer()],
+        program.programId
+      );
+      return program.methods.createGovernance(buildGovernanceConfig())
+        .accounts({
+          authority: user.publicKey,
+          realm: lockRealmPda,
+          governedAccount,
+          tokenOwnerRecord: tokenOwnerRecordPda,
+          voterWeightRecord: null,
+        } as any)
+        .signers([user])
+        .rpc()
+        .then(() => governancePda);
+    };
+
+    // users[15] 在 lockRealm 中存有 5 个治理代币
+    await setRealmConfig(buildRealmConfig(1_000 * ONE_TOKEN, supplyFraction));
+    const realmAccount = await program.account.realm.fetch(lockRealmPda);
+    if (!realmAccount.config.minCommunityWeightToCreateGovernance.eq(new anchor.BN(1_000 * ONE_TOKEN))) {
+      throw new Error("❌ minCommunityWeightToCreateGovernance 应已更新为 1000");
+    }
+    await expectAnchorError(createGovernanceByUser(), "InsufficientWeightToCreateGovernance");
+
+    await setRealmConfig(buildRealmConfig(5 * ONE_TOKEN, supplyFraction));
+    lockRealmGovernance = await createGovernanceByUser();
+
+    const governanceAccount = await program.account.governance.fetch(lockRealmGovernance);
+    if (!governanceAccount.realm.equals(lockRealmPda)) {
+      throw new Error("❌ Governance 应属于 lockRealm");
+    }
+    console.log("✅ set realm config 校验通过！");
+  });
+
+  it("set governance config", async () => {
+    const governance = await createGovernance(buildGovernanceConfig());
+    const newConfig = buildGovernanceConfig({ votingBaseTime: 999 });
+
+    // Governance PDA 未签名时不能直接修改配置
+    const ix = await program.methods.setGovernanceConfig(newConfig)
+      .accounts({ governance } as any)
+      .instruction();
+    ix.keys.forEach(key => { key.isSigner = false; });
+    await expectAnchorError(provider.sendAndConfirm(new Transaction().add(ix)), "ConstraintSigner");
+
+    // 通过提案执行，由程序以 Governance PDA 代签
+    const instructionData = {
+      programId: program.programId,
+      data: ix.data,
+      accounts: [
+        { pubkey: governance, isSigner: true, isWritable: true },
+      ],
+    };
+    const proposer = users[13];
+    const proposal = await createProposal(proposer, governance);
+    await addTransaction(proposer, governance, proposal, instructionData);
+    await signOffProposal(proposer, governance, proposal);
+    // 10 * 20 = 200 枚，达到 10% 阈值后提前通过
+    for (let i = 0; i < 10; i++) {
+      await castVote(users[i], governance, proposal, { yes: {} });
+    }
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("executing" in proposalAccount.state)) {
+      throw new Error("❌ 提案应已提前通过并进入 Executing");
+    }
+
+    await executeTransaction(governance, proposal, instructionData);
+
+    const governanceAccount = await program.account.governance.fetch(governance);
+    if (governanceAccount.config.votingBaseTime !== 999) {
+      throw new Error(`❌ votingBaseTime 应更新为 999, got: ${governanceAccount.config.votingBaseTime}`);
+    }
+    console.log("✅ set governance config 校验通过！");
+  });
+
+  it("council veto and abstain votes", async () => {
+    // 理事会最大票权为 3，60% 的否决门槛需要 2 票
+    const governance = await createGovernance(buildGovernanceConfig({
+      councilVoteThreshold: { yesVotePercentage: [60] },
+      councilVetoVoteThreshold: { yesVotePercentage: [60] },
+    }), councilRealmPda);
+
+    // 否决票达到门槛，社区提案进入 Vetoed
+    const vetoedProposal = await createProposal(users[3], governance, { realm: councilRealmPda });
+    await signOffProposal(users[3], governance, vetoedProposal, mint, councilRealmPda);
+    await castVote(users[0], governance, vetoedProposal, { veto: {} }, councilMint, councilRealmPda);
+    let proposalAccount = await program.account.proposal.fetch(vetoedProposal);
+    if (!("voting" in proposalAccount.state) || !proposalAccount.vetoVoteWeight.eq(new anchor.BN(1))) {
+      throw new Error("❌ 一票否决未达门槛，提案应仍在投票中");
+    }
+    await castVote(users[1], governance, vetoedProposal, { veto: {} }, councilMint, councilRealmPda);
+    proposalAccount = await program.account.proposal.fetch(vetoedProposal);
+    if (!("vetoed" in proposalAccount.state) || proposalAccount.closedAt === null) {
+      throw new Error("❌ 否决票达到门槛，提案应进入 Vetoed");
+    }
+    const governanceAccount = await program.account.governance.fetch(governance);
+    if (governanceAccount.activeProposalCount.toNumber() !== 0) {
+      throw new Error("❌ 被否决的提案不应再计入 activeProposalCount");
+    }
+
+    // 理事会提案不能被否决
+    const councilProposal = await createProposal(users[0], governance, {
+      governingMint: councilMint,
+      realm: councilRealmPda,
+    });
+    await signOffProposal(users[0], governance, councilProposal, councilMint, councilRealmPda);
+    await expectAnchorError(
+      castVote(users[1], governance, councilProposal, { veto: {} }, councilMint, councilRealmPda),
+      "InvalidVetoVote"
+    );
+
+    // 投票期间撤回否决票，从 vetoVoteWeight 中扣除；弃权票只计入参与度
+    const proposal = await createProposal(users[4], governance, { realm: councilRealmPda });
+    await signOffProposal(users[4], governance, proposal, mint, councilRealmPda);
+    await castVote(users[2], governance, proposal, { veto: {} }, councilMint, councilRealmPda);
+    await castVote(users[5], governance, proposal, { abstain: {} }, mint, councilRealmPda);
+    proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!proposalAccount.vetoVoteWeight.eq(new anchor.BN(1))) {
+      throw new Error("❌ vetoVoteWeight 应为 1");
+    }
+    if (
+      !proposalAccount.abstainVoteWeight.eq(new anchor.BN(20 * ONE_TOKEN))
+      || !proposalAccount.castVoteWeight.eq(new anchor.BN(20 * ONE_TOKEN))
+      || !proposalAccount.options[0].voteWeight.eq(new anchor.BN(0))
+      || !proposalAccount.noVoteWeight.eq(new anchor.BN(0))
+    ) {
+      throw new Error("❌ 弃权票只应计入 abstainVoteWeight 与 castVoteWeight");
+    }
+
+    await relinquishVote(users[2], governance, proposal, councilMint, councilRealmPda);
+    proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!proposalAccount.vetoVoteWeight.eq(new anchor.BN(0))) {
+      throw new Error("❌ 撤回后 vetoVoteWeight 应为 0");
+    }
+    const councilRecordPda = getTokenOwnerRecordPda(users[2].publicKey, councilMint, councilRealmPda);
+    const voteRecord = await program.account.voteRecord.fetchNullable(getVoteRecordPda(proposal, councilRecordPda));
+    if (voteRecord !== null) {
+      throw new Error("❌ 投票期间撤回后投票记录应已关闭");
+    }
+    console.log("✅ council veto and abstain votes 校验通过！");
+  });
+
+  it("council vote tipping modes", async () => {
+    // 理事会最大票权为 3，30% 的门槛需要 1 票
+    const councilConfig = (councilVoteTipping: any) => buildGovernanceConfig({
+      councilVoteThreshold: { yesVotePercentage: [30] },
+      councilVoteTipping,
+    });
+    const castCouncilVote = (voter: Keypair, governance: PublicKey, proposal: PublicKey, vote: any) =>
+      castVote(voter, governance, proposal, vote, councilMint, councilRealmPda);
+    const createCouncilProposal = async (proposer: Keypair, governance: PublicKey) => {
+      const proposal = await createProposal(proposer, governance, {
+        governingMint: councilMint,
+        realm: councilRealmPda,
+      });
+      await signOffProposal(proposer, governance, proposal, councilMint, councilRealmPda);
+      return proposal;
+    };
+    const expectState = async (proposal: PublicKey, state: string, message: string) => {
+      const proposalAccount = await program.account.proposal.fetch(proposal);
+      if (!(state in proposalAccount.state)) {
+        throw new Error(`❌ ${message}, got: ${JSON.stringify(proposalAccount.state)}`);
+      }
+    };
+
+    // Early：赞成票达到门槛且多于反对票即通过
+    const earlyGovernance = await createGovernance(councilConfig({ early: {} }), councilRealmPda);
+    const earlyProposal = await createCouncilProposal(users[0], earlyGovernance);
+    await castCouncilVote(users[0], earlyGovernance, earlyProposal, { yes: {} });
+    await expectState(earlyProposal, "completed", "Early 模式下一票赞成即应通过");
+
+    // Strict：剩余票权全部投反对也无法改变结果时才通过
+    const strictGovernance = await createGovernance(councilConfig({ strict: {} }), councilRealmPda);
+    const strictProposal = await createCouncilProposal(users[0], strictGovernance);
+    await castCouncilVote(users[0], strictGovernance, strictProposal, { yes: {} });
+    await expectState(strictProposal, "voting", "Strict 模式下剩余票权仍可翻盘，不应通过");
+    await castCouncilVote(users[1], strictGovernance, strictProposal, { no: {} });
+    await expectState(strictProposal, "voting", "赞成与反对持平，应继续投票");
+    await castCouncilVote(users[2], strictGovernance, strictProposal, { yes: {} });
+    await expectState(strictProposal, "completed", "全部投票后赞成多于反对，应通过");
+
+    // Strict：赞成票已无法胜出时提前否决
+    const defeatedProposal = await createCouncilProposal(users[1], strictGovernance);
+    await castCouncilVote(users[0], strictGovernance, defeatedProposal, { no: {} });
+    await expectState(defeatedProposal, "voting", "一票反对时仍可能通过");
+    await castCouncilVote(users[1], strictGovernance, defeatedProposal, { no: {} });
+    await expectState(defeatedProposal, "defeated", "反对票已不可逆转，应提前否决");
+    console.log("✅ council vote tipping modes 校验通过！");
+  });
+
+  it("strict vote tipping on community mint", async () => {
+    // 社区代币 6 位小数，最大票权为供应量 2000 枚（以最小单位计），门槛 10% 即 200 枚
+    const governance = await createGovernance(buildGovernanceConfig({
+      communityVoteTipping: { strict: {} },
+      votingBaseTime: 8,
+      votingCoolOffTime: 0,
+    }));
+    const proposal = await createProposal(users[16], governance);
+    await signOffProposal(users[16], governance, proposal);
+
+    // 10 * 20 = 200 枚达到门槛，但仍有 1800 枚未投票，可能翻盘，不应提前通过
+    for (let i = 0; i < 10; i++) {
+      await castVote(users[i], governance, proposal, { yes: {} });
+    }
+    let proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("voting" in proposalAccount.state)) {
+      throw new Error(`❌ Strict 模式下剩余票权仍可翻盘，不应提前结束, got: ${JSON.stringify(proposalAccount.state)}`);
+    }
+
+    // 投票期结束后按门槛计算结果，最大票权与计票使用同一单位
+    await waitForVotingEnd(governance, proposal);
+    await finalizeVote(governance, proposal);
+    proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("completed" in proposalAccount.state)) {
+      throw new Error("❌ 投票期结束后赞成票达到门槛，应通过");
+    }
+    if (!proposalAccount.maxVoteWeight?.eq(new anchor.BN(2_000 * ONE_TOKEN))) {
+      throw new Error(`❌ maxVoteWeight 应为 2000 枚, got: ${proposalAccount.maxVoteWeight?.toString()}`);
+    }
+    console.log("✅ strict vote tipping on community mint 校验通过！");
+  });
+
+  it("relinquish vote refunds rent only to the rent payer", async () => {
+    const governance = await createGovernance(buildGovernanceConfig());
+    const proposal = await createProposal(users[14], governance);
+    await signOffProposal(users[14], governance, proposal);
+
+    const voter = users[16];
+    await castVote(voter, governance, proposal, { yes: {} });
+    const voteTokenOwnerRecordPda = getTokenOwnerRecordPda(voter.publicKey);
+
+    // 投票记录的租金只能退还给投票时的付款人
+    await expectAnchorError(
+      program.methods.relinquishVote()
+        .accounts({
+          proposal,
+          governance,
+          realm: realmPda,
+          signer: voter.publicKey,
+          tokenOwnerRecord: voteTokenOwnerRecordPda,
+          voteRecord: getVoteRecordPda(proposal, voteTokenOwnerRecordPda),
+          rentPayer: users[17].publicKey,
+        } as any)
+        .signers([voter])
+        .rpc(),
+      "InvalidRentPayer"
+    );
+
+    const voteRecordRent = await connection.getBalance(getVoteRecordPda(proposal, voteTokenOwnerRecordPda));
+    const voterBalance = await connection.getBalance(voter.publicKey);
+    await relinquishVote(voter, governance, proposal);
+    // 撤回交易手续费由 provider 钱包支付，投票人余额只增加投票记录租金
+    if (await connection.getBalance(voter.publicKey) !== voterBalance + voteRecordRent) {
+      throw new Error("❌ 投票记录租金应退还给投票人");
+    }
+    console.log("✅ relinquish vote rent payer 校验通过！");
+  });
+
+  it("vote weight is snapshotted at vote time", async () => {
+    const governance = await createGovernance(buildGovernanceConfig());
+    const proposal = await createProposal(users[14], governance);
+    await signOffProposal(users[14], governance, proposal);
+
+    const voter = users[15];
+    const voteTokenOwnerRecordPda = getTokenOwnerRecordPda(voter.publicKey);
+    await castVote(voter, governance, proposal, { yes: {} });
+
+    const voteRecord = await program.account.voteRecord.fetch(getVoteRecordPda(proposal, voteTokenOwnerRecordPda));
+    if (!voteRecord.voteWeight.eq(new anchor.BN(20 * ONE_TOKEN))) {
+      throw new Error("❌ 投票记录应快照投票时的存款 20");
+    }
+
+    const changeDeposit = (method: "depositGoverningTokens" | "withdrawGoverningTokens", amount: number) =>
+      program.methods[method](new anchor.BN(amount * ONE_TOKEN))
+        .accounts({
+          user: voter.publicKey,
+          mint: mint,
+          realm: realmPda,
+          governingTokenHolding: communityTokenPda,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        } as any)
+        .signers([voter])
+        .rpc();
+
+    // 存在未释放投票时不能减少存款，避免取出后换钱包重复投票
+    await expectAnchorError(
+      changeDeposit("withdrawGoverningTokens", 5),
+      "AllVotesMustBeRelinquishedToWithdrawGoverningTokens"
+    );
+
+    // 追加存款不影响已投出的票
+    await changeDeposit("depositGoverningTokens", 10);
+    let proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!proposalAccount.options[0].voteWeight.eq(new anchor.BN(20 * ONE_TOKEN))) {
+      throw new Error("❌ 追加存款后赞成票仍应为 20");
+    }
+
+    // 投票期间撤回，按快照的票权从计票中扣除
+    await relinquishVote(voter, governance, proposal);
+    proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!proposalAccount.options[0].voteWeight.eq(new anchor.BN(0))) {
+      throw new Error("❌ 撤回后赞成票应为 0");
+    }
+
+    await changeDeposit("withdrawGoverningTokens", 10);
+    const tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(voteTokenOwnerRecordPda);
+    if (!tokenOwnerRecordAccount.governingTokenDepositAmount.eq(new anchor.BN(20 * ONE_TOKEN))) {
+      throw new Error("❌ 撤回投票后应可取出，存款应为 20");
+    }
+    console.log("✅ vote weight snapshot 校验通过！");
+  });
+
+  it("relinquish vote while proposal is executing", async () => {
+    const governance = await createGovernance(buildGovernanceConfig());
+    await fundNativeTreasury(governance, LAMPORTS_PER_SOL / 10);
+    const proposer = users[17];
+    const proposal = await createProposal(proposer, governance);
+    await addTransaction(proposer, governance, proposal, buildTreasuryTransferInstruction(governance, proposer.publicKey));
+    await signOffProposal(proposer, governance, proposal);
+    for (let i = 0; i < 10; i++) {
+      await castVote(users[i], governance, proposal, { yes: {} });
+    }
+    let proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("executing" in proposalAccount.state)) {
+      throw new Error("❌ 提案应已通过并进入 Executing");
+    }
+
+    // 交易尚未执行，投票人也可以撤回投票，计票保持不变
+    const voter = users[0];
+    const voteTokenOwnerRecordPda = getTokenOwnerRecordPda(voter.publicKey);
+    const unrelinquishedVotesCount = (await program.account.tokenOwnerRecord.fetch(voteTokenOwnerRecordPda))
+      .unrelinquishedVotesCount;
+    await relinquishVote(voter, governance, proposal);
+
+    const voteRecord = await program.account.voteRecord.fetch(getVoteRecordPda(proposal, voteTokenOwnerRecordPda));
+    if (!voteRecord.isRelinquished) {
+      throw new Error("❌ 投票记录应标记为已撤回");
+    }
+    const tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(voteTokenOwnerRecordPda);
+    if (tokenOwnerRecordAccount.unrelinquishedVotesCount.toNumber() !== unrelinquishedVotesCount.toNumber() - 1) {
+      throw new Error("❌ unrelinquishedVotesCount 应减少 1");
+    }
+    proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("executing" in proposalAccount.state) || !proposalAccount.options[0].voteWeight.eq(new anchor.BN(200 * ONE_TOKEN))) {
+      throw new Error("❌ 投票结束后撤回不应改变状态与计票");
+    }
+    console.log("✅ relinquish vote while executing 校验通过！");
+  });
+
+  it("voter weight addin requires a voter weight record", async () => {
+    const user = users[15];
+    const tokenConfig = (voterWeightAddin: PublicKey | null) => ({
+      tokenType: { liquid: {} },
+      lockAuthorities: [payer],
+      voterWeightAddin,
+      maxVoterWeightAddin: null,
+    } as any);
+    const setVoterWeightAddin = (voterWeightAddin: PublicKey | null) =>
+      program.methods.setRealmConfig(
+        {
+          minCommunityWeightToCreateGovernance: new anchor.BN(5 * ONE_TOKEN),
+          communityMintMaxVoterWeightSource: { supplyFraction: [new anchor.BN(10_000_000)] },
+        } as any,
+        tokenConfig(voterWeightAddin),
+        tokenConfig(null)
+      )
+        .accounts({
+          authority: payer,
+          realm: lockRealmPda,
+        } as any)
+        .rpc();
+
+    // 配置插件后，票权只能来自插件写入的 VoterWeightRecord
+    const addin = Keypair.generate().publicKey;
+    await setVoterWeightAddin(addin);
+    const realmConfigAccount = await program.account.realmConfigAccount.fetch(
+      PublicKey.findProgramAddressSync(
+        [lockRealmId.toArrayLike(Buffer, "le", 8), Buffer.from("realm_config")],
+        program.programId
+      )[0]
+    );
+    if (!realmConfigAccount.communityTokenConfig.voterWeightAddin?.equals(addin)) {
+      throw new Error("❌ voterWeightAddin 应已更新");
+    }
+
+    await expectAnchorError(
+      createProposal(user, lockRealmGovernance, { realm: lockRealmPda }),
+      "MissingVoterWeightRecord"
+    );
+    // 不属于插件程序的账户不能作为 VoterWeightRecord
+    await expectAnchorError(
+      createProposal(user, lockRealmGovernance, {
+        realm: lockRealmPda,
+        accounts: { voterWeightRecord: user.publicKey },
+      }),
+      "InvalidVoterWeightRecord"
+    );
+
+    // 移除插件后恢复按存款计算票权
+    await setVoterWeightAddin(null);
+    const proposal = await createProposal(user, lockRealmGovernance, { realm: lockRealmPda });
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("draft" in proposalAccount.state)) {
+      throw new Error("❌ 移除插件后应能创建提案");
+    }
+    console.log("✅ voter weight addin 校验通过！");
+  });
+
+  it("close token owner record", async () => {
+    const closeTokenOwnerRecord = (user: Keypair) =>
+      program.methods.closeTokenOwnerRecord()
+        .accounts({
+          authority: user.publicKey,
+          tokenOwnerRecord: getTokenOwnerRecordPda(user.publicKey, mint, lockRealmPda),
+          rentPayer: user.publicKey,
+        } as any)
+        .signers([user])
+        .rpc();
+
+    // 没有存款、投票和提案的记录可以关闭，租金退还
+    const emptyUser = users[18];
+    await program.methods.createTokenOwnerRecord()
+      .accounts({
+        user: emptyUser.publicKey,
+        mint: mint,
+        realm: lockRealmPda,
+      } as any)
+      .signers([emptyUser])
+      .rpc();
+    await closeTokenOwnerRecord(emptyUser);
+    const closedRecord = await program.account.tokenOwnerRecord.fetchNullable(
+      getTokenOwnerRecordPda(emptyUser.publicKey, mint, lockRealmPda)
+    );
+    if (closedRecord !== null) {
+      throw new Error("❌ 记录应已关闭");
+    }
+
+    // users[15] 在 lockRealm 创建过提案，取消提案并取出存款后仍不能关闭，否则重建后提案序号会冲突
+    const user = users[15];
+    const tokenOwnerRecordPda = getTokenOwnerRecordPda(user.publicKey, mint, lockRealmPda);
+    const proposal = getProposalPda(lockRealmGovernance, tokenOwnerRecordPda, new anchor.BN(0));
+    await cancelProposal(user, lockRealmGovernance, proposal, mint, lockRealmPda);
+    await program.methods.withdrawGoverningTokens(new anchor.BN(5 * ONE_TOKEN))
+      .accounts({
+        user: user.publicKey,
+        mint: mint,
+        realm: lockRealmPda,
+        governingTokenHolding: getCommunityTokenPda(mint, lockRealmPda),
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+      } as any)
+      .signers([user])
+      .rpc();
+
+    await expectAnchorError(closeTokenOwnerRecord(user), "TokenOwnerRecordHasProposals");
+    console.log("✅ close token owner record 校验通过！");
+  });
+
+  it("proposal deposit exempt count and defaults", async () => {
+    const proposer = users[17];
+    const getDepositAmount = async (proposal: PublicKey) =>
+      (await program.account.proposalDeposit.fetch(getProposalDepositPda(proposer.publicKey, proposal))).amount;
+
+    // 免押金数量为 1：第一个活跃提案免押金，之后每多一个活跃提案多缴 1 份
+    const governance = await createGovernance(buildGovernanceConfig({ depositExemptProposalCount: 1 }));
+    const proposals: PublicKey[] = [];
+    for (const expected of [0, 100_000_000, 200_000_000]) {
+      const proposal = await createProposal(proposer, governance);
+      const amount = await getDepositAmount(proposal);
+      if (!amount.eq(new anchor.BN(expected))) {
+        throw new Error(`❌ 押金应为 ${expected}, got: ${amount.toString()}`);
+      }
+      proposals.push(proposal);
+    }
+
+    // 提案结束后活跃数量减少，押金随之回落
+    await cancelProposal(proposer, governance, proposals[0]);
+    const nextProposal = await createProposal(proposer, governance);
+    if (!(await getDepositAmount(nextProposal)).eq(new anchor.BN(200_000_000))) {
+      throw new Error("❌ 取消一个提案后，第 3 个活跃提案的押金应为 2 份");
+    }
+
+    // 未配置时免押金数量默认为 10，押金默认 0.1 SOL
+    const defaultGovernance = await createGovernance(buildGovernanceConfig({
+      depositExemptProposalCount: null,
+      proposalDepositAmount: null,
+    }));
+    const defaultProposal = await createProposal(proposer, defaultGovernance);
+    if (!(await getDepositAmount(defaultProposal)).eq(new anchor.BN(0))) {
+      throw new Error("❌ 默认前 10 个活跃提案免押金");
+    }
+
+    // 代币押金没有默认数额，必须显式配置
+    await expectAnchorError(
+      createGovernance(buildGovernanceConfig({
+        proposalDepositType: { governingTokens: {} },
+        proposalDepositAmount: null,
+      })),
+      "MissingProposalDepositAmount"
+    );
+    console.log("✅ proposal deposit exempt count 校验通过！");
+  });
+
+  it("proposal deposit in governing tokens", async () => {
+    const proposer = users[18];
+    const governance = await createGovernance(buildGovernanceConfig({
+      proposalDepositType: { governingTokens: {} },
+      proposalDepositAmount: new anchor.BN(5 * ONE_TOKEN),
+    }));
+
+    const tokenOwnerRecordPda = getTokenOwnerRecordPda(proposer.publicKey);
+    const proposal = getProposalPda(
+      governance,
+      tokenOwnerRecordPda,
+      (await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda)).proposalIndex
+    );
+    const proposalDepositPda = getProposalDepositPda(proposer.publicKey, proposal);
+    const proposerTokenAccount = getAssociatedTokenAddressSync(mint, proposer.publicKey);
+    const depositTokenAccount = getAssociatedTokenAddressSync(mint, proposalDepositPda, true);
+    const tokenAccounts = {
+      depositTokenAccount,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+
+    // 未传代币账户时无法收取代币押金
+    await expectAnchorError(createProposal(proposer, governance), "MissingProposalDepositTokenAccount");
+
+    const balanceBefore = (await getAccount(connection, proposerTokenAccount)).amount;
+    await createProposal(proposer, governance, {
+      accounts: {
+        ...tokenAccounts,
+        proposerTokenAccount,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      },
+    });
+
+    const depositBalance = (await getAccount(connection, depositTokenAccount)).amount;
+    const proposalDepositAccount = await program.account.proposalDeposit.fetch(proposalDepositPda);
+    if (depositBalance !== BigInt(5 * ONE_TOKEN) || !proposalDepositAccount.amount.eq(new anchor.BN(5 * ONE_TOKEN))) {
+      throw new Error(`❌ 押金账户应托管 5 个治理代币, got: ${depositBalance}`);
+    }
+    if ((await getAccount(connection, proposerTokenAccount)).amount !== balanceBefore - BigInt(5 * ONE_TOKEN)) {
+      throw new Error("❌ 提案人应支付 5 个治理代币");
+    }
+
+    // 取消后退还代币押金，押金代币账户随之关闭
+    await cancelProposal(proposer, governance, proposal);
+    await refundProposalDeposit(proposer.publicKey, governance, proposal, {
+      ...tokenAccounts,
+      depositPayerTokenAccount: proposerTokenAccount,
+    });
+
+    if ((await getAccount(connection, proposerTokenAccount)).amount !== balanceBefore) {
+      throw new Error("❌ 退还后提案人代币余额应恢复");
+    }
+    if (await connection.getAccountInfo(depositTokenAccount) !== null) {
+      throw new Error("❌ 押金代币账户应已关闭");
+    }
+    if (await connection.getAccountInfo(proposalDepositPda) !== null) {
+      throw new Error("❌ 押金账户应已关闭");
+    }
+    console.log("✅ proposal deposit in governing tokens 校验通过！");
+  });
+
+  it("slash proposal deposits", async () => {
+    // 投票率低于 50% 而失败、被否决或投票中取消的提案罚没押金
+    const slashConfig = buildGovernanceConfig({
+      councilVetoVoteThreshold: { yesVotePercentage: [60] },
+      communityVoteTipping: { disabled: {} },
+      votingBaseTime: 8,
+      votingCoolOffTime: 0,
+      depositSlashTurnoutPercentage: 50,
+    });
+    const governance = await createGovernance(slashConfig, councilRealmPda);
+    const treasury = getNativeTreasuryPda(governance);
+    await fundNativeTreasury(governance, LAMPORTS_PER_SOL / 100);
+
+    const startProposal = async (proposer: Keypair, gov: PublicKey = governance) => {
+      const proposal = await createProposal(proposer, gov, { realm: councilRealmPda });
+      await signOffProposal(proposer, gov, proposal, mint, councilRealmPda);
+      return proposal;
+    };
+
+    /** 退还押金并校验押金去向：罚没时押金进入金库，账户租金始终退还给押金支付人 **/
+    const expectRefund = async (proposer: Keypair, gov: PublicKey, proposal: PublicKey, slashed: boolean) => {
+      const depositPda = getProposalDepositPda(proposer.publicKey, proposal);
+      const amount = (await program.account.proposalDeposit.fetch(depositPda)).amount.toNumber();
+      const depositLamports = await connection.getBalance(depositPda);
+      const payerBalance = await connection.getBalance(proposer.publicKey);
+      const treasuryBalance = await connection.getBalance(getNativeTreasuryPda(gov));
+
+      await refundProposalDeposit(proposer.publicKey, gov, proposal, {
+        nativeTreasury: getNativeTreasuryPda(gov),
+      });
+
+      const slashedAmount = slashed ? amount : 0;
+      if (await connection.getBalance(getNativeTreasuryPda(gov)) !== treasuryBalance + slashedAmount) {
+        throw new Error(`❌ 金库应收到 ${slashedAmount} lamports`);
+      }
+      if (await connection.getBalance(proposer.publicKey) !== payerBalance + depositLamports - slashedAmount) {
+        throw new Error(`❌ 押金支付人应收到 ${depositLamports - slashedAmount} lamports`);
+      }
+    };
+
+    // 进入投票时快照罚没门槛
+    const vetoedProposal = await startProposal(users[6]);
+    const proposalAccount = await program.account.proposal.fetch(vetoedProposal);
+    if (proposalAccount.depositSlashTurnoutPercentage !== 50) {
+      throw new Error("❌ 进入投票时应快照 depositSlashTurnoutPercentage");
+    }
+
+    // 被否决
+    await castVote(users[0], governance, vetoedProposal, { veto: {} }, councilMint, councilRealmPda);
+    await castVote(users[1], governance, vetoedProposal, { veto: {} }, councilMint, councilRealmPda);
+    await expectRefund(users[6], governance, vetoedProposal, true);
+
+    // 投票中取消
+    const cancelledProposal = await startProposal(users[7]);
+    await cancelProposal(users[7], governance, cancelledProposal, mint, councilRealmPda);
+    await expectRefund(users[7], governance, cancelledProposal, true);
+
+    // 草稿阶段取消不罚没
+    const draftProposal = await createProposal(users[7], governance, { realm: councilRealmPda });
+    await cancelProposal(users[7], governance, draftProposal, mint, councilRealmPda);
+    await expectRefund(users[7], governance, draftProposal, false);
+
+    // 投票率 20 枚 / 2000 枚低于 50%，失败后罚没
+    const lowTurnoutProposal = await startProposal(users[8]);
+    await castVote(users[9], governance, lowTurnoutProposal, { yes: {} }, mint, councilRealmPda);
+    await expectAnchorError(
+      refundProposalDeposit(users[8].publicKey, governance, lowTurnoutProposal, { nativeTreasury: treasury }),
+      "ProposalStillInVoting"
+    );
+    await waitForVotingEnd(governance, lowTurnoutProposal);
+    await finalizeVote(governance, lowTurnoutProposal, councilRealmPda);
+    const defeated = await program.account.proposal.fetch(lowTurnoutProposal);
+    if (!("defeated" in defeated.state)) {
+      throw new Error("❌ 提案应未通过");
+    }
+    if (
+      !defeated.castVoteWeight.eq(new anchor.BN(20 * ONE_TOKEN))
+      || !defeated.maxVoteWeight?.eq(new anchor.BN(2_000 * ONE_TOKEN))
+    ) {
+      throw new Error("❌ 投票率应按 20 枚 / 2000 枚计算");
+    }
+    await expectRefund(users[8], governance, lowTurnoutProposal, true);
+
+    // 金库尚未创建且押金低于免租金额时，押金连同账户租金一并转入金库
+    const noTreasuryGovernance = await createGovernance(
+      { ...slashConfig, proposalDepositAmount: new anchor.BN(1_000) },
+      councilRealmPda
+    );
+    const noTreasury = getNativeTreasuryPda(noTreasuryGovernance);
+    const orphanProposal = await startProposal(users[10], noTreasuryGovernance);
+    await cancelProposal(users[10], noTreasuryGovernance, orphanProposal, mint, councilRealmPda);
+    const orphanDepositPda = getProposalDepositPda(users[10].publicKey, orphanProposal);
+    const orphanDepositLamports = await connection.getBalance(orphanDepositPda);
+    const orphanPayerBalance = await connection.getBalance(users[10].publicKey);
+    await refundProposalDeposit(users[10].publicKey, noTreasuryGovernance, orphanProposal, {
+      nativeTreasury: noTreasury,
+    });
+    if (await connection.getBalance(noTreasury) !== orphanDepositLamports) {
+      throw new Error("❌ 金库应收到押金账户的全部 lamports");
+    }
+    if (await connection.getBalance(users[10].publicKey) !== orphanPayerBalance) {
+      throw new Error("❌ 押金已全部转入金库，押金支付人余额不应变化");
+    }
+    if (await connection.getAccountInfo(orphanDepositPda) !== null) {
+      throw new Error("❌ 押金账户应已关闭");
+    }
+    console.log("✅ slash proposal deposits 校验通过！");
+  });
+
+  it("expire draft proposal", async () => {
+    const proposer = users[19];
+    const governance = await createGovernance(buildGovernanceConfig({
+      maxDraftTime: 3,
+      depositSlashTurnoutPercentage: 50,
+    }));
+    const proposal = await createProposal(proposer, governance);
+    const tokenOwnerRecordPda = getTokenOwnerRecordPda(proposer.publicKey);
+    const outstandingProposalCount = (await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda))
+      .outstandingProposalCount;
+
+    const expireDraftProposal = () => program.methods.expireDraftProposal()
+      .accounts({
+        governance,
+        proposal,
+        tokenOwnerRecord: tokenOwnerRecordPda,
+      } as any)
+      .rpc();
+
+    await expectAnchorError(expireDraftProposal(), "ProposalDraftNotExpired");
+
+    const draftAt = (await program.account.proposal.fetch(proposal)).draftAt.toNumber();
+    await waitUntilChainTime(draftAt + 3);
+
+    // 草稿超时后不能再进入投票，任何人都可以将其标记为过期
+    await expectAnchorError(signOffProposal(proposer, governance, proposal), "ProposalDraftExpired");
+    await expireDraftProposal();
+
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    if (!("expired" in proposalAccount.state) || proposalAccount.closedAt === null) {
+      throw new Error("❌ 提案应进入 Expired 并记录 closedAt");
+    }
+    const governanceAccount = await program.account.governance.fetch(governance);
+    if (governanceAccount.activeProposalCount.toNumber() !== 0) {
+      throw new Error("❌ 过期提案不应再计入 activeProposalCount");
+    }
+    const tokenOwnerRecordAccount = await program.account.tokenOwnerRecord.fetch(tokenOwnerRecordPda);
+    if (tokenOwnerRecordAccount.outstandingProposalCount.toNumber() !== outstandingProposalCount.toNumber() - 1) {
+      throw new Error("❌ outstandingProposalCount 应减少 1");
+    }
+
+    // 过期视为放弃而非否决，押金全额退还
+    const depositPda = getProposalDepositPda(proposer.publicKey, proposal);
+    const depositLamports = await connection.getBalance(depositPda);
+    const proposerBalance = await connection.getBalance(proposer.publicKey);
+    await refundProposalDeposit(proposer.publicKey, governance, proposal);
+    if (await connection.getBalance(proposer.publicKey) !== proposerBalance + depositLamports) {
+      throw new Error("❌ 过期提案的押金应全额退还");
+    }
+    console.log("✅ expire draft proposal 校验通过！");
+  });
+
+  it("flag transaction error and retry", async () => {
+    const HOLD_UP_TIME = 5;
+    // 执行窗口为 hold_up_time，且不少于程序中的 MIN_TRANSACTION_EXECUTION_WINDOW
+    const EXECUTION_WINDOW = Math.max(HOLD_UP_TIME, 60);
+
+    /** 创建带金库转账交易的提案并投票通过，返回进入 Executing 的提案 **/
+    const createExecutingProposal = async (proposer: Keypair, transactionRetryTime: number, transactionsCount = 1) => {
+      const governance = await createGovernance(buildGovernanceConfig({ transactionRetryTime }));
+      await fundNativeTreasury(governance, LAMPORTS_PER_SOL / 10);
+      const instructionData = buildTreasuryTransferInstruction(governance, proposer.publicKey);
+      const proposal = await createProposal(proposer, governance);
+      for (let i = 0; i < transactionsCount; i++) {
+        await addTransaction(proposer, governance, proposal, instructionData, HOLD_UP_TIME, i);
+      }
+      await signOffProposal(proposer, governance, proposal);
+      for (let i = 0; i < 10; i++) {
+        await castVote(users[i], governance, proposal, { yes: {} });
+      }
+      const proposalAccount = await program.account.proposal.fetch(proposal);
+      if (!("executing" in proposalAccount.state)) {
+        throw new Error("❌ 提案应已通过并进入 Executing");
+      }
+      return {
+        proposer,
+        governance,
+        proposal,
+        instructionData,
+        votingCompletedAt: proposalAccount.votingCompletedAt!.toNumber(),
+      };
+    };
+
+    const flagTransactionError = (
+      authority: Keypair,
+      target: { proposer: Keypair, governance: PublicKey, proposal: PublicKey },
+      transactionIndex = 0
+    ) =>
+      program.methods.flagTransactionError()
+        .accounts({
+          authority: authority.publicKey,
+          governance: target.governance,
+          tokenOwnerRecord: getTokenOwnerRecordPda(target.proposer.publicKey),
+          proposal: target.proposal,
+          proposalTransaction: getProposalTransactionPda(target.proposal, 0, transactionIndex),
+        } as any)
+        .signers([authority])
+        .rpc();
+
+    const expectProposalState = async (proposal: PublicKey, state: string, closed: boolean, message: string) => {
+      const proposalAccount = await program.account.proposal.fetch(proposal);
+      if (!(state in proposalAccount.state) || (proposalAccount.closedAt !== null) !== closed) {
+        throw new Error(`❌ ${message}, got: ${JSON.stringify(proposalAccount.state)}`);
+      }
+    };
+
+    // 重试时长为 0 的提案
+    const noRetry = await createExecutingProposal(users[11], 0, 2);
+    await expectAnchorError(flagTransactionError(users[11], noRetry), "CannotExecuteTransactionWithinHoldUpTime");
+    // 交易可执行后的执行窗口内，任何人都可以执行，发起人不能标记失败
+    await waitUntilChainTime(noRetry.votingCompletedAt + HOLD_UP_TIME);
+    await expectAnchorError(flagTransactionError(users[11], noRetry), "CannotFlagTransactionWithinExecutionWindow");
+
+    // 包含两条交易、允许重试的提案，与上一个提案一起等待执行窗口结束
+    const withRetry = await createExecutingProposal(users[12], 3_600, 2);
+    await waitUntilChainTime(withRetry.votingCompletedAt + HOLD_UP_TIME + EXECUTION_WINDOW);
+    // 只有提案发起人或其委托人可以标记
+    await expectAnchorError(
+      flagTransactionError(users[5], noRetry),
+      "GoverningTokenOwnerOrDelegateMustSign"
+    );
+    await flagTransactionError(users[11], noRetry);
+
+    await expectProposalState(noRetry.proposal, "executionFailed", true, "提案应进入 ExecutionFailed");
+    const proposalTransactionAccount = await program.account.proposalTransaction.fetch(
+      getProposalTransactionPda(noRetry.proposal, 0, 0)
+    );
+    if (!("error" in proposalTransactionAccount.executionStatus) || proposalTransactionAccount.errorFlaggedAt === null) {
+      throw new Error("❌ 交易应标记为 Error");
+    }
+    await expectAnchorError(flagTransactionError(users[11], noRetry), "TransactionAlreadyFlagged");
+    // 重试期限已过，失败的交易和其余交易都不能再执行
+    await expectAnchorError(
+      executeTransaction(noRetry.governance, noRetry.proposal, noRetry.instructionData),
+      "TransactionRetryTimeExpired"
+    );
+    await expectAnchorError(
+      executeTransaction(noRetry.governance, noRetry.proposal, noRetry.instructionData, 1),
+      "TransactionRetryTimeExpired"
+    );
+
+    // 重试期限内重新执行第一条交易，成功后提案恢复为 Executing
+    await flagTransactionError(users[12], withRetry);
+    await expectAnchorError(
+      executeTransaction(withRetry.governance, withRetry.proposal, withRetry.instructionData, 1),
+      "InvalidTransactionExecutionOrder"
+    );
+    await executeTransaction(withRetry.governance, withRetry.proposal, withRetry.instructionData);
+    await expectProposalState(withRetry.proposal, "executing", false, "重试成功后提案应恢复为 Executing");
+
+    // 第二条交易在前一条执行后才轮到执行，执行窗口从此时重新计算
+    await expectAnchorError(
+      flagTransactionError(users[12], withRetry, 1),
+      "CannotFlagTransactionWithinExecutionWindow"
+    );
+    await executeTransaction(withRetry.governance, withRetry.proposal, withRetry.instructionData, 1);
+    await expectProposalState(withRetry.proposal, "completed", true, "全部交易执行后提案应完成");
+    const retriedTransaction = await program.account.proposalTransaction.fetch(
+      getProposalTransactionPda(withRetry.proposal, 0, 0)
+    );
+    if (!("success" in retriedTransaction.executionStatus)) {
+      throw new Error("❌ 重试执行成功后交易状态应为 Success");
+    }
+    console.log("✅ flag transaction error 校验通过！");
+  });
+
 });